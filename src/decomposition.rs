@@ -1,6 +1,14 @@
-use crate::Matrix;
-use num::{Num, Signed};
+use crate::{ColumnVector, Complex, LinalgError, Matrix};
+use num::{Float, Num};
 use core::fmt::Debug;
+use core::ops::Neg;
+
+pub(crate) fn abs<T>(x: T) -> T
+where
+    T: Num + Neg<Output = T> + PartialOrd,
+{
+    if x < T::zero() { -x } else { x }
+}
 
 #[derive(Debug, Clone)]
 pub struct PartialPivLu<T, const N: usize>
@@ -10,34 +18,49 @@ where
     pub l: Matrix<T, N, N>,
     pub u: Matrix<T, N, N>,
     pub p: Matrix<T, N, N>, // Permutation matrix
+    pub perm: [usize; N],   // Row `i` of `P * A` is row `perm[i]` of `A`
+    pub sign: T,            // Sign of the permutation, `det(P)`
+    row_scale: [T; N],      // Largest absolute entry of row `i` of `P * A`
 }
 
 impl<T, const N: usize> Matrix<T, N, N>
 where
-    T: Num + Copy + Debug + Signed + PartialOrd,
+    T: Num + Copy + Debug + Neg<Output = T> + PartialOrd,
 {
-    pub fn lu_decomposition(&self) -> (Matrix<T, N, N>, Matrix<T, N, N>) {
+    /// LU decomposition with partial (row) pivoting, such that `P * A = L * U`.
+    pub fn partial_piv_lu(&self) -> PartialPivLu<T, N> {
         let mut l = Matrix::<T, N, N>::identity(); // Lower triangular matrix
         let mut u = *self; // Upper triangular matrix (copied from the original matrix)
+        let mut perm = [0; N];
+        for (i, row) in perm.iter_mut().enumerate() {
+            *row = i;
+        }
+        let mut sign = T::one();
 
         for k in 0..N {
             // Partial pivoting: Find the row with the largest absolute value in column `k`
             let mut pivot_row = k;
             for i in (k + 1)..N {
-                if u.elements[i][k].abs() > u.elements[pivot_row][k].abs() {
+                if abs(u.elements[i][k]) > abs(u.elements[pivot_row][k]) {
                     pivot_row = i;
                 }
             }
 
-            // Swap rows in U
+            // Swap rows in U, in the permutation and in the already computed part of L
             if pivot_row != k {
                 u.elements.swap(k, pivot_row);
-
-                // Also swap the rows in L (but only the columns before k)
+                perm.swap(k, pivot_row);
                 for j in 0..k {
+                    let tmp = l.elements[k][j];
                     l.elements[k][j] = l.elements[pivot_row][j];
-                    l.elements[pivot_row][j] = T::zero();
+                    l.elements[pivot_row][j] = tmp;
                 }
+                sign = -sign;
+            }
+
+            // A zero pivot means the whole column below it is zero already
+            if u.elements[k][k] == T::zero() {
+                continue;
             }
 
             // Perform Gaussian elimination to zero out below-diagonal elements in column `k`
@@ -45,12 +68,1664 @@ where
                 let factor = u.elements[i][k] / u.elements[k][k];
                 l.elements[i][k] = factor; // Update L
 
-                for j in k..N {
+                u.elements[i][k] = T::zero();
+                for j in (k + 1)..N {
                     u.elements[i][j] = u.elements[i][j] - factor * u.elements[k][j];
                 }
             }
         }
 
-        (l, u)
+        let mut p = Matrix::<T, N, N>::zeros();
+        let mut row_scale = [T::zero(); N];
+        for (i, &row) in perm.iter().enumerate() {
+            p.elements[i][row] = T::one();
+            for &x in &self.elements[row] {
+                if abs(x) > row_scale[i] {
+                    row_scale[i] = abs(x);
+                }
+            }
+        }
+
+        PartialPivLu { l, u, p, perm, sign, row_scale }
+    }
+
+    /// Alias of [`Matrix::partial_piv_lu`].
+    pub fn lu_decomposition(&self) -> PartialPivLu<T, N> {
+        self.partial_piv_lu()
+    }
+
+    /// Determinant by fraction-free (Bareiss) elimination with row pivoting.
+    ///
+    /// Every division is exact, so the result is exact for integer element types.
+    pub fn bareiss_determinant(&self) -> T {
+        let mut mat = self.elements;
+        let mut sign = T::one();
+        let mut previous_pivot = T::one();
+
+        for k in 0..N {
+            let mut pivot = k;
+            for i in (k + 1)..N {
+                if abs(mat[i][k]) > abs(mat[pivot][k]) {
+                    pivot = i;
+                }
+            }
+            if mat[pivot][k] == T::zero() {
+                return T::zero();
+            }
+            if pivot != k {
+                mat.swap(k, pivot);
+                sign = -sign;
+            }
+
+            for i in (k + 1)..N {
+                for j in (k + 1)..N {
+                    mat[i][j] = (mat[i][j] * mat[k][k] - mat[i][k] * mat[k][j]) / previous_pivot;
+                }
+            }
+            previous_pivot = mat[k][k];
+        }
+
+        sign * previous_pivot
+    }
+}
+
+impl<T, const N: usize> PartialPivLu<T, N>
+where
+    T: Num + Copy + Debug,
+{
+    /// Determinant of the factorized matrix, `det(P) * prod(diag(U))`.
+    pub fn determinant(&self) -> T {
+        let mut det = self.sign;
+        for i in 0..N {
+            det = det * self.u.elements[i][i];
+        }
+        det
+    }
+}
+
+/// Element types that know which algorithm gives them a reliable determinant.
+///
+/// `f32` and `f64` go through the LU decomposition, and `Complex<f32>`/`Complex<f64>`
+/// through an LU that pivots on the modulus. Signed integer types use fraction-free
+/// Bareiss elimination, so no precision is lost to truncating division.
+///
+/// [`Matrix::determinant`] requires `T: Determinant`, so code generic over `T: Float`
+/// has to add that bound, or call `partial_piv_lu().determinant()` directly:
+///
+/// ```
+/// use core::fmt::Debug;
+/// use num::Float;
+/// use numbrs::{matrix, Determinant, Matrix};
+///
+/// fn det<T: Float + Debug + Determinant>(m: Matrix<T, 2, 2>) -> T {
+///     m.determinant()
+/// }
+///
+/// fn det_lu<T: Float + Debug>(m: Matrix<T, 2, 2>) -> T {
+///     m.partial_piv_lu().determinant()
+/// }
+///
+/// let m = matrix![[4.0, 7.0], [2.0, 6.0]];
+/// assert_eq!(det(m), 10.0);
+/// assert_eq!(det_lu(m), 10.0);
+/// ```
+pub trait Determinant: Num + Copy + Debug {
+    fn determinant_of<const N: usize>(matrix: &Matrix<Self, N, N>) -> Self;
+
+    /// Determinant of the `n x n` matrix stored row-major in `data`, which is overwritten.
+    fn determinant_of_rows(data: &mut [Self], n: usize) -> Self;
+}
+
+macro_rules! impl_determinant_lu {
+    ($($t:ty),*) => {
+        $(
+            impl Determinant for $t {
+                fn determinant_of<const N: usize>(matrix: &Matrix<Self, N, N>) -> Self {
+                    matrix.partial_piv_lu().determinant()
+                }
+
+                fn determinant_of_rows(data: &mut [Self], n: usize) -> Self {
+                    lu_determinant_rows(data, n, |x: $t| x.abs())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_determinant_bareiss {
+    ($($t:ty),*) => {
+        $(
+            impl Determinant for $t {
+                fn determinant_of<const N: usize>(matrix: &Matrix<Self, N, N>) -> Self {
+                    matrix.bareiss_determinant()
+                }
+
+                fn determinant_of_rows(data: &mut [Self], n: usize) -> Self {
+                    bareiss_determinant_rows(data, n)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_determinant_complex_lu {
+    ($($t:ty),*) => {
+        $(
+            impl Determinant for Complex<$t> {
+                fn determinant_of<const N: usize>(matrix: &Matrix<Self, N, N>) -> Self {
+                    complex_lu_determinant(matrix)
+                }
+
+                fn determinant_of_rows(data: &mut [Self], n: usize) -> Self {
+                    lu_determinant_rows(data, n, |z: Self| z.norm())
+                }
+            }
+        )*
+    };
+}
+
+impl_determinant_lu!(f32, f64);
+impl_determinant_complex_lu!(f32, f64);
+impl_determinant_bareiss!(i8, i16, i32, i64, i128, isize);
+
+/// LU elimination on a complex matrix, pivoting on the largest modulus in each column.
+#[allow(clippy::needless_range_loop)]
+fn complex_lu_determinant<T, const N: usize>(matrix: &Matrix<Complex<T>, N, N>) -> Complex<T>
+where
+    T: Float + Debug,
+{
+    let mut u = matrix.elements;
+    let mut det = Complex::new(T::one(), T::zero());
+
+    for k in 0..N {
+        let mut pivot = k;
+        for i in (k + 1)..N {
+            if u[i][k].norm() > u[pivot][k].norm() {
+                pivot = i;
+            }
+        }
+        if u[pivot][k].norm() == T::zero() {
+            return Complex::new(T::zero(), T::zero());
+        }
+        if pivot != k {
+            u.swap(k, pivot);
+            det = -det;
+        }
+        det = det * u[k][k];
+
+        for i in (k + 1)..N {
+            let factor = u[i][k] / u[k][k];
+            for j in (k + 1)..N {
+                u[i][j] = u[i][j] - factor * u[k][j];
+            }
+        }
+    }
+
+    det
+}
+
+/// LU elimination on a row-major `n x n` matrix, pivoting on the largest `magnitude` in each column.
+fn lu_determinant_rows<T, R, F>(mat: &mut [T], n: usize, magnitude: F) -> T
+where
+    T: Num + Copy + Neg<Output = T>,
+    R: PartialOrd,
+    F: Fn(T) -> R,
+{
+    let mut det = T::one();
+
+    for k in 0..n {
+        let mut pivot = k;
+        for i in (k + 1)..n {
+            if magnitude(mat[i * n + k]) > magnitude(mat[pivot * n + k]) {
+                pivot = i;
+            }
+        }
+        if mat[pivot * n + k] == T::zero() {
+            return T::zero();
+        }
+        if pivot != k {
+            for j in 0..n {
+                mat.swap(k * n + j, pivot * n + j);
+            }
+            det = -det;
+        }
+        det = det * mat[k * n + k];
+
+        for i in (k + 1)..n {
+            let factor = mat[i * n + k] / mat[k * n + k];
+            for j in (k + 1)..n {
+                mat[i * n + j] = mat[i * n + j] - factor * mat[k * n + j];
+            }
+        }
+    }
+
+    det
+}
+
+/// Row-major counterpart of [`Matrix::bareiss_determinant`].
+fn bareiss_determinant_rows<T>(mat: &mut [T], n: usize) -> T
+where
+    T: Num + Copy + Neg<Output = T> + PartialOrd,
+{
+    let mut sign = T::one();
+    let mut previous_pivot = T::one();
+
+    for k in 0..n {
+        let mut pivot = k;
+        for i in (k + 1)..n {
+            if abs(mat[i * n + k]) > abs(mat[pivot * n + k]) {
+                pivot = i;
+            }
+        }
+        if mat[pivot * n + k] == T::zero() {
+            return T::zero();
+        }
+        if pivot != k {
+            for j in 0..n {
+                mat.swap(k * n + j, pivot * n + j);
+            }
+            sign = -sign;
+        }
+
+        for i in (k + 1)..n {
+            for j in (k + 1)..n {
+                mat[i * n + j] = (mat[i * n + j] * mat[k * n + k] - mat[i * n + k] * mat[k * n + j]) / previous_pivot;
+            }
+        }
+        previous_pivot = mat[k * n + k];
+    }
+
+    sign * previous_pivot
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Determinant,
+{
+    pub fn determinant(&self) -> T {
+        T::determinant_of(self)
+    }
+}
+
+impl<T, const N: usize> PartialPivLu<T, N>
+where
+    T: Float + Debug,
+{
+    /// A pivot is treated as zero when it is non-finite or below `N * eps` relative to the
+    /// largest entry of its own row of `P * A`.
+    ///
+    /// Scaling per row keeps well-conditioned mixed-scale matrices such as `diag(100, 1e-6)`
+    /// solvable; how ill-conditioned a matrix is, is left to `condition_number`.
+    fn is_singular(&self) -> bool {
+        let n = T::from(N).unwrap();
+        (0..N).any(|k| {
+            let pivot = self.u.elements[k][k].abs();
+            !pivot.is_finite() || pivot <= T::epsilon() * n * self.row_scale[k]
+        })
+    }
+
+    /// Solve `A * x = b` using forward and back substitution.
+    pub fn solve(&self, b: ColumnVector<T, N>) -> Result<ColumnVector<T, N>, LinalgError> {
+        self.solve_matrix(b.0).map(ColumnVector::new)
+    }
+
+    /// Solve `A * X = B` for every column of `B` at once.
+    pub fn solve_matrix<const K: usize>(
+        &self,
+        b: Matrix<T, N, K>,
+    ) -> Result<Matrix<T, N, K>, LinalgError> {
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+
+        // Apply the row permutation: y = P * b
+        let mut x = Matrix::<T, N, K>::zeros();
+        for i in 0..N {
+            x.elements[i] = b.elements[self.perm[i]];
+        }
+
+        // Forward substitution with the unit lower triangular L
+        for i in 0..N {
+            for k in 0..i {
+                let factor = self.l.elements[i][k];
+                for c in 0..K {
+                    x.elements[i][c] = x.elements[i][c] - factor * x.elements[k][c];
+                }
+            }
+        }
+
+        // Back substitution with the upper triangular U
+        for i in (0..N).rev() {
+            for k in (i + 1)..N {
+                let factor = self.u.elements[i][k];
+                for c in 0..K {
+                    x.elements[i][c] = x.elements[i][c] - factor * x.elements[k][c];
+                }
+            }
+            let pivot = self.u.elements[i][i];
+            for c in 0..K {
+                x.elements[i][c] = x.elements[i][c] / pivot;
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// Inverse of the factorized matrix, obtained by solving `A * X = I`.
+    pub fn inverse(&self) -> Result<Matrix<T, N, N>, LinalgError> {
+        self.solve_matrix(Matrix::identity())
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Debug,
+{
+    /// Solve `A * x = b` through a partially pivoted LU decomposition of `A`.
+    pub fn solve(&self, b: ColumnVector<T, N>) -> Result<ColumnVector<T, N>, LinalgError> {
+        self.partial_piv_lu().solve(b)
+    }
+
+    /// Inverse of a square matrix through its partially pivoted LU decomposition.
+    pub fn inverse(&self) -> Result<Matrix<T, N, N>, LinalgError> {
+        self.partial_piv_lu().inverse()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cholesky<T, const N: usize>
+where
+    T: Num + Copy + Debug,
+{
+    pub l: Matrix<T, N, N>, // Lower triangular factor, A = L * Lᵀ
+}
+
+#[derive(Debug, Clone)]
+pub struct Ldlt<T, const N: usize>
+where
+    T: Num + Copy + Debug,
+{
+    pub l: Matrix<T, N, N>, // Unit lower triangular factor, A = L * D * Lᵀ
+    pub d: [T; N],          // Diagonal of D
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Debug,
+{
+    /// Cholesky decomposition `A = L * Lᵀ` of a symmetric positive-definite matrix.
+    ///
+    /// Only the lower triangle of `A` is read.
+    pub fn cholesky(&self) -> Result<Cholesky<T, N>, LinalgError> {
+        let mut l = Matrix::<T, N, N>::zeros();
+
+        for j in 0..N {
+            let mut diagonal = self.elements[j][j];
+            for k in 0..j {
+                diagonal = diagonal - l.elements[j][k] * l.elements[j][k];
+            }
+            if diagonal.is_nan() || diagonal <= T::zero() {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            let pivot = diagonal.sqrt();
+            l.elements[j][j] = pivot;
+
+            for i in (j + 1)..N {
+                let mut value = self.elements[i][j];
+                for k in 0..j {
+                    value = value - l.elements[i][k] * l.elements[j][k];
+                }
+                l.elements[i][j] = value / pivot;
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+
+    /// LDLᵀ decomposition of a symmetric positive semi-definite matrix.
+    ///
+    /// Unlike [`Matrix::cholesky`] this takes no square roots and accepts zero pivots,
+    /// as long as the rest of their column vanishes too. Only the lower triangle of `A` is read.
+    #[allow(clippy::needless_range_loop)]
+    pub fn ldlt(&self) -> Result<Ldlt<T, N>, LinalgError> {
+        let mut l = Matrix::<T, N, N>::identity();
+        let mut d = [T::zero(); N];
+
+        let mut scale = T::zero();
+        for i in 0..N {
+            scale = scale.max(self.elements[i][i].abs());
+        }
+        let tolerance = T::epsilon() * T::from(N).unwrap() * scale;
+
+        for j in 0..N {
+            let mut pivot = self.elements[j][j];
+            for k in 0..j {
+                pivot = pivot - l.elements[j][k] * l.elements[j][k] * d[k];
+            }
+            if pivot.is_nan() || pivot < -tolerance {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            if pivot <= tolerance {
+                pivot = T::zero();
+            }
+            d[j] = pivot;
+
+            for i in (j + 1)..N {
+                let mut value = self.elements[i][j];
+                for k in 0..j {
+                    value = value - l.elements[i][k] * l.elements[j][k] * d[k];
+                }
+                if pivot == T::zero() {
+                    // A semi-definite matrix has an all-zero column wherever the pivot vanishes
+                    if value.abs() > tolerance {
+                        return Err(LinalgError::NotPositiveDefinite);
+                    }
+                    l.elements[i][j] = T::zero();
+                } else {
+                    l.elements[i][j] = value / pivot;
+                }
+            }
+        }
+
+        Ok(Ldlt { l, d })
+    }
+}
+
+impl<T, const N: usize> Cholesky<T, N>
+where
+    T: Float + Debug,
+{
+    /// Solve `A * x = b` with one forward and one back substitution.
+    pub fn solve(&self, b: ColumnVector<T, N>) -> ColumnVector<T, N> {
+        ColumnVector::new(self.solve_matrix(b.0))
+    }
+
+    /// Solve `A * X = B` for every column of `B` at once.
+    pub fn solve_matrix<const K: usize>(&self, b: Matrix<T, N, K>) -> Matrix<T, N, K> {
+        let mut x = b;
+
+        // L * y = b
+        for i in 0..N {
+            for k in 0..i {
+                let factor = self.l.elements[i][k];
+                for c in 0..K {
+                    x.elements[i][c] = x.elements[i][c] - factor * x.elements[k][c];
+                }
+            }
+            let pivot = self.l.elements[i][i];
+            for c in 0..K {
+                x.elements[i][c] = x.elements[i][c] / pivot;
+            }
+        }
+
+        // Lᵀ * x = y
+        for i in (0..N).rev() {
+            for k in (i + 1)..N {
+                let factor = self.l.elements[k][i];
+                for c in 0..K {
+                    x.elements[i][c] = x.elements[i][c] - factor * x.elements[k][c];
+                }
+            }
+            let pivot = self.l.elements[i][i];
+            for c in 0..K {
+                x.elements[i][c] = x.elements[i][c] / pivot;
+            }
+        }
+
+        x
+    }
+
+    pub fn inverse(&self) -> Matrix<T, N, N> {
+        self.solve_matrix(Matrix::identity())
+    }
+
+    pub fn determinant(&self) -> T {
+        let mut det = T::one();
+        for i in 0..N {
+            det = det * self.l.elements[i][i];
+        }
+        det * det
+    }
+
+    /// Natural logarithm of the determinant, which does not overflow for large matrices.
+    pub fn log_determinant(&self) -> T {
+        let mut log_det = T::zero();
+        for i in 0..N {
+            log_det = log_det + self.l.elements[i][i].ln();
+        }
+        log_det + log_det
+    }
+}
+
+impl<T, const N: usize> Ldlt<T, N>
+where
+    T: Float + Debug,
+{
+    /// Returns `true` when every pivot is strictly positive.
+    pub fn is_positive_definite(&self) -> bool {
+        self.d.iter().all(|&d| d > T::zero())
+    }
+
+    /// Solve `A * x = b`, which requires every pivot to be non-zero.
+    pub fn solve(&self, b: ColumnVector<T, N>) -> Result<ColumnVector<T, N>, LinalgError> {
+        self.solve_matrix(b.0).map(ColumnVector::new)
+    }
+
+    /// Solve `A * X = B` for every column of `B` at once.
+    pub fn solve_matrix<const K: usize>(
+        &self,
+        b: Matrix<T, N, K>,
+    ) -> Result<Matrix<T, N, K>, LinalgError> {
+        if self.d.iter().any(|&d| d == T::zero()) {
+            return Err(LinalgError::Singular);
+        }
+
+        let mut x = b;
+
+        // L * z = b
+        for i in 0..N {
+            for k in 0..i {
+                let factor = self.l.elements[i][k];
+                for c in 0..K {
+                    x.elements[i][c] = x.elements[i][c] - factor * x.elements[k][c];
+                }
+            }
+        }
+
+        // D * y = z
+        for i in 0..N {
+            for c in 0..K {
+                x.elements[i][c] = x.elements[i][c] / self.d[i];
+            }
+        }
+
+        // Lᵀ * x = y
+        for i in (0..N).rev() {
+            for k in (i + 1)..N {
+                let factor = self.l.elements[k][i];
+                for c in 0..K {
+                    x.elements[i][c] = x.elements[i][c] - factor * x.elements[k][c];
+                }
+            }
+        }
+
+        Ok(x)
+    }
+
+    pub fn determinant(&self) -> T {
+        self.d.iter().fold(T::one(), |det, &d| det * d)
+    }
+}
+
+/// Householder QR decomposition `A = Q * R` of a matrix with at least as many rows as columns.
+#[derive(Debug, Clone)]
+pub struct Qr<T, const M: usize, const N: usize>
+where
+    T: Num + Copy + Debug,
+{
+    qr: Matrix<T, M, N>, // R on and above the diagonal, Householder vectors below it
+    tau: [T; N],         // Householder coefficients, H_k = I - tau_k * v_k * v_kᵀ
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float + Debug,
+{
+    /// Householder QR decomposition, `A = Q * R`.
+    ///
+    /// A matrix with fewer rows than columns does not compile; [`Matrix::try_qr`] is the
+    /// checked path for code generic over the shape.
+    ///
+    /// ```compile_fail,E0080
+    /// use numbrs::{matrix, Matrix};
+    ///
+    /// let qr = matrix![[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]].qr();
+    /// ```
+    pub fn qr(&self) -> Qr<T, M, N> {
+        const { assert!(M >= N, "Matrix must have at least as many rows as columns for QR") };
+        self.try_qr().expect("Matrix must have at least as many rows as columns for QR.")
+    }
+
+    pub fn try_qr(&self) -> Result<Qr<T, M, N>, LinalgError> {
+        if M < N {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        let mut qr = Qr { qr: *self, tau: [T::zero(); N] };
+        for k in 0..N {
+            qr.householder_step(k);
+        }
+        Ok(qr)
+    }
+
+    /// QR decomposition with column pivoting, `A * P = Q * R`.
+    ///
+    /// The diagonal of `R` is non-increasing in magnitude, which reveals the numerical rank.
+    ///
+    /// Like [`Matrix::qr`], a matrix with fewer rows than columns does not compile; see
+    /// [`Matrix::try_col_piv_qr`] for the checked path.
+    pub fn col_piv_qr(&self) -> ColPivQr<T, M, N> {
+        const { assert!(M >= N, "Matrix must have at least as many rows as columns for QR") };
+        self.try_col_piv_qr()
+            .expect("Matrix must have at least as many rows as columns for QR.")
     }
-}
\ No newline at end of file
+
+    pub fn try_col_piv_qr(&self) -> Result<ColPivQr<T, M, N>, LinalgError> {
+        if M < N {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        let mut qr = Qr { qr: *self, tau: [T::zero(); N] };
+        let mut perm = [0; N];
+        for (j, column) in perm.iter_mut().enumerate() {
+            *column = j;
+        }
+
+        for k in 0..N {
+            // Bring the remaining column with the largest norm to position `k`
+            let mut pivot = k;
+            let mut pivot_norm = -T::one();
+            for j in k..N {
+                let mut norm = T::zero();
+                for i in k..M {
+                    norm = norm.hypot(qr.qr.elements[i][j]);
+                }
+                if norm > pivot_norm {
+                    pivot = j;
+                    pivot_norm = norm;
+                }
+            }
+            if pivot != k {
+                for i in 0..M {
+                    qr.qr.elements[i].swap(k, pivot);
+                }
+                perm.swap(k, pivot);
+            }
+
+            qr.householder_step(k);
+        }
+
+        Ok(ColPivQr { qr, perm })
+    }
+}
+
+impl<T, const M: usize, const N: usize> Qr<T, M, N>
+where
+    T: Float + Debug,
+{
+    /// Annihilate column `k` below the diagonal and update the columns to its right.
+    fn householder_step(&mut self, k: usize) {
+        let qr = &mut self.qr;
+
+        let mut norm = T::zero();
+        for i in k..M {
+            norm = norm.hypot(qr.elements[i][k]);
+        }
+        if norm == T::zero() {
+            self.tau[k] = T::zero();
+            return;
+        }
+
+        // Reflect x onto alpha * e_1, picking the sign that avoids cancellation
+        let x0 = qr.elements[k][k];
+        let alpha = if x0 > T::zero() { -norm } else { norm };
+        let v0 = x0 - alpha;
+        for i in (k + 1)..M {
+            qr.elements[i][k] = qr.elements[i][k] / v0;
+        }
+        let tau = (alpha - x0) / alpha;
+        self.tau[k] = tau;
+        qr.elements[k][k] = alpha;
+
+        for j in (k + 1)..N {
+            let mut s = qr.elements[k][j];
+            for i in (k + 1)..M {
+                s = s + qr.elements[i][k] * qr.elements[i][j];
+            }
+            s = s * tau;
+            qr.elements[k][j] = qr.elements[k][j] - s;
+            for i in (k + 1)..M {
+                qr.elements[i][j] = qr.elements[i][j] - s * qr.elements[i][k];
+            }
+        }
+    }
+
+    /// Apply `H_k` to every column of `target`, in place.
+    fn reflect<const K: usize>(&self, k: usize, target: &mut Matrix<T, M, K>) {
+        for j in 0..K {
+            let mut s = target.elements[k][j];
+            for i in (k + 1)..M {
+                s = s + self.qr.elements[i][k] * target.elements[i][j];
+            }
+            s = s * self.tau[k];
+            target.elements[k][j] = target.elements[k][j] - s;
+            for i in (k + 1)..M {
+                target.elements[i][j] = target.elements[i][j] - s * self.qr.elements[i][k];
+            }
+        }
+    }
+
+    /// Compute `Qᵀ * b` without forming `Q`.
+    pub fn q_transpose_mul<const K: usize>(&self, b: Matrix<T, M, K>) -> Matrix<T, M, K> {
+        let mut result = b;
+        for k in 0..N {
+            self.reflect(k, &mut result);
+        }
+        result
+    }
+
+    /// Compute `Q * b` without forming `Q`.
+    pub fn q_mul<const K: usize>(&self, b: Matrix<T, M, K>) -> Matrix<T, M, K> {
+        let mut result = b;
+        for k in (0..N).rev() {
+            self.reflect(k, &mut result);
+        }
+        result
+    }
+
+    /// The full orthogonal factor, `M x M`.
+    pub fn q(&self) -> Matrix<T, M, M> {
+        self.q_mul(Matrix::identity())
+    }
+
+    /// The first `N` columns of `Q`, such that `A = thin_q() * thin_r()`.
+    pub fn thin_q(&self) -> Matrix<T, M, N> {
+        self.q_mul(Matrix::identity())
+    }
+
+    /// The full upper trapezoidal factor, `M x N`, with zero rows below the first `N`.
+    pub fn r(&self) -> Matrix<T, M, N> {
+        let mut r = Matrix::<T, M, N>::zeros();
+        for i in 0..N {
+            for j in i..N {
+                r.elements[i][j] = self.qr.elements[i][j];
+            }
+        }
+        r
+    }
+
+    /// The square upper triangular factor, `N x N`.
+    pub fn thin_r(&self) -> Matrix<T, N, N> {
+        let mut r = Matrix::<T, N, N>::zeros();
+        for i in 0..N {
+            for j in i..N {
+                r.elements[i][j] = self.qr.elements[i][j];
+            }
+        }
+        r
+    }
+
+    /// Minimize `||A * x - b||` by solving `R * x = Qᵀ * b`.
+    ///
+    /// Fails with [`LinalgError::Singular`] when `A` does not have full column rank.
+    pub fn solve_least_squares(
+        &self,
+        b: ColumnVector<T, M>,
+    ) -> Result<ColumnVector<T, N>, LinalgError> {
+        let mut scale = T::zero();
+        for i in 0..N {
+            for j in i..N {
+                scale = scale.max(self.qr.elements[i][j].abs());
+            }
+        }
+        let tolerance = T::epsilon() * T::from(M).unwrap() * scale;
+        if (0..N).any(|k| self.qr.elements[k][k].abs() <= tolerance) {
+            return Err(LinalgError::Singular);
+        }
+
+        let y = self.q_transpose_mul(b.0);
+        let mut x = Matrix::<T, N, 1>::zeros();
+        for i in (0..N).rev() {
+            let mut value = y.elements[i][0];
+            for k in (i + 1)..N {
+                value = value - self.qr.elements[i][k] * x.elements[k][0];
+            }
+            x.elements[i][0] = value / self.qr.elements[i][i];
+        }
+
+        Ok(ColumnVector::new(x))
+    }
+}
+
+/// Column-pivoted (rank-revealing) QR decomposition `A * P = Q * R`.
+#[derive(Debug, Clone)]
+pub struct ColPivQr<T, const M: usize, const N: usize>
+where
+    T: Num + Copy + Debug,
+{
+    qr: Qr<T, M, N>,
+    pub perm: [usize; N], // Column `j` of `A * P` is column `perm[j]` of `A`
+}
+
+impl<T, const M: usize, const N: usize> ColPivQr<T, M, N>
+where
+    T: Float + Debug,
+{
+    pub fn q(&self) -> Matrix<T, M, M> {
+        self.qr.q()
+    }
+
+    pub fn thin_q(&self) -> Matrix<T, M, N> {
+        self.qr.thin_q()
+    }
+
+    pub fn r(&self) -> Matrix<T, M, N> {
+        self.qr.r()
+    }
+
+    pub fn thin_r(&self) -> Matrix<T, N, N> {
+        self.qr.thin_r()
+    }
+
+    /// The column permutation as a matrix, so that `A * P = Q * R`.
+    pub fn p(&self) -> Matrix<T, N, N> {
+        let mut p = Matrix::<T, N, N>::zeros();
+        for (j, &column) in self.perm.iter().enumerate() {
+            p.elements[column][j] = T::one();
+        }
+        p
+    }
+
+    /// A rank tolerance of `max(M, N) * eps * |R[0][0]|`, suitable for most inputs.
+    pub fn default_tolerance(&self) -> T {
+        if N == 0 {
+            return T::zero();
+        }
+        T::epsilon() * T::from(M.max(N)).unwrap() * self.qr.qr.elements[0][0].abs()
+    }
+
+    /// Number of diagonal entries of `R` whose magnitude exceeds `tolerance`.
+    pub fn rank(&self, tolerance: T) -> usize {
+        (0..N)
+            .take_while(|&k| self.qr.qr.elements[k][k].abs() > tolerance)
+            .count()
+    }
+
+    /// Basic solution of `A * x ≈ b` for a possibly rank-deficient `A`.
+    ///
+    /// Only the `rank(tolerance)` leading pivot columns are used, and the components of `x`
+    /// belonging to the remaining columns are set to zero.
+    #[allow(clippy::needless_range_loop)]
+    pub fn solve(&self, b: ColumnVector<T, M>, tolerance: T) -> ColumnVector<T, N> {
+        let rank = self.rank(tolerance);
+        let y = self.qr.q_transpose_mul(b.0);
+
+        let mut z = [T::zero(); N];
+        for i in (0..rank).rev() {
+            let mut value = y.elements[i][0];
+            for k in (i + 1)..rank {
+                value = value - self.qr.qr.elements[i][k] * z[k];
+            }
+            z[i] = value / self.qr.qr.elements[i][i];
+        }
+
+        let mut x = Matrix::<T, N, 1>::zeros();
+        for (j, &column) in self.perm.iter().enumerate() {
+            x.elements[column][0] = z[j];
+        }
+        ColumnVector::new(x)
+    }
+}
+
+/// Eigen decomposition `A = V * diag(eigenvalues) * Vᵀ` of a symmetric matrix.
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<T, const N: usize>
+where
+    T: Num + Copy + Debug,
+{
+    pub eigenvalues: [T; N],           // In ascending order
+    pub eigenvectors: Matrix<T, N, N>, // Column `i` is the unit eigenvector of `eigenvalues[i]`
+}
+
+const JACOBI_MAX_SWEEPS: usize = 64;
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Debug,
+{
+    /// Eigenvalues and orthonormal eigenvectors of a symmetric matrix, by cyclic Jacobi rotations.
+    ///
+    /// The matrix is assumed to be symmetric. Jacobi is slower than tridiagonal QL for large `N`,
+    /// but it is simple, allocation free and accurate even for tiny eigenvalues.
+    #[allow(clippy::needless_range_loop)]
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T, N>, LinalgError> {
+        let mut a = *self;
+        let mut v = Matrix::<T, N, N>::identity();
+
+        // The Frobenius norm is invariant under the rotations
+        let mut norm = T::zero();
+        for i in 0..N {
+            for j in 0..N {
+                norm = norm.hypot(a.elements[i][j]);
+            }
+        }
+        let tolerance = T::epsilon() * norm;
+
+        let mut converged = false;
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let mut off_diagonal = T::zero();
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    off_diagonal = off_diagonal.hypot(a.elements[p][q]);
+                }
+            }
+            if off_diagonal <= tolerance {
+                converged = true;
+                break;
+            }
+
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    let apq = a.elements[p][q];
+                    if apq == T::zero() {
+                        continue;
+                    }
+
+                    // Rotation angle that zeroes a[p][q], taking the smaller root for stability
+                    let two = T::one() + T::one();
+                    let theta = (a.elements[q][q] - a.elements[p][p]) / (two * apq);
+                    let t = theta.signum() / (theta.abs() + theta.hypot(T::one()));
+                    let c = T::one() / t.hypot(T::one());
+                    let s = t * c;
+
+                    for k in 0..N {
+                        let akp = a.elements[k][p];
+                        let akq = a.elements[k][q];
+                        a.elements[k][p] = c * akp - s * akq;
+                        a.elements[k][q] = s * akp + c * akq;
+                    }
+                    for k in 0..N {
+                        let apk = a.elements[p][k];
+                        let aqk = a.elements[q][k];
+                        a.elements[p][k] = c * apk - s * aqk;
+                        a.elements[q][k] = s * apk + c * aqk;
+                    }
+                    a.elements[p][q] = T::zero();
+                    a.elements[q][p] = T::zero();
+
+                    for k in 0..N {
+                        let vkp = v.elements[k][p];
+                        let vkq = v.elements[k][q];
+                        v.elements[k][p] = c * vkp - s * vkq;
+                        v.elements[k][q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+        if !converged {
+            return Err(LinalgError::NoConvergence { iterations: JACOBI_MAX_SWEEPS });
+        }
+
+        let mut eigenvalues = [T::zero(); N];
+        for i in 0..N {
+            eigenvalues[i] = a.elements[i][i];
+        }
+
+        // Selection sort keeps eigenvalues and eigenvector columns paired
+        for i in 0..N {
+            let mut smallest = i;
+            for j in (i + 1)..N {
+                if eigenvalues[j] < eigenvalues[smallest] {
+                    smallest = j;
+                }
+            }
+            if smallest != i {
+                eigenvalues.swap(i, smallest);
+                for k in 0..N {
+                    v.elements[k].swap(i, smallest);
+                }
+            }
+        }
+
+        Ok(SymmetricEigen { eigenvalues, eigenvectors: v })
+    }
+}
+
+/// Hessenberg form `A = Q * H * Qᵀ`, where `H` is zero below its first subdiagonal.
+#[derive(Debug, Clone)]
+pub struct Hessenberg<T, const N: usize>
+where
+    T: Num + Copy + Debug,
+{
+    pub h: Matrix<T, N, N>, // Upper Hessenberg matrix
+    pub q: Matrix<T, N, N>, // Orthogonal similarity transform
+}
+
+/// Eigenvalues and right eigenvectors of a general real matrix.
+#[derive(Debug, Clone)]
+pub struct Eigen<T, const N: usize>
+where
+    T: Num + Copy + Debug,
+{
+    pub eigenvalues: [Complex<T>; N],           // Sorted by real part, then imaginary part
+    pub eigenvectors: Matrix<Complex<T>, N, N>, // Column `i` is the unit eigenvector of `eigenvalues[i]`
+}
+
+const HQR_MAX_ITERATIONS: usize = 30; // Per eigenvalue
+const INVERSE_ITERATION_STEPS: usize = 3;
+
+/// `|a|` with the sign of `b`.
+fn with_sign<T: Float>(a: T, b: T) -> T {
+    if b >= T::zero() { a.abs() } else { -a.abs() }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Debug,
+{
+    /// Reduce to upper Hessenberg form with Householder similarity transforms.
+    #[allow(clippy::needless_range_loop)]
+    pub fn hessenberg(&self) -> Hessenberg<T, N> {
+        let mut h = *self;
+        let mut q = Matrix::<T, N, N>::identity();
+        let mut v = [T::zero(); N];
+
+        for k in 0..N.saturating_sub(2) {
+            let mut norm = T::zero();
+            for i in (k + 1)..N {
+                norm = norm.hypot(h.elements[i][k]);
+            }
+            if norm == T::zero() {
+                continue;
+            }
+
+            let x0 = h.elements[k + 1][k];
+            let alpha = if x0 > T::zero() { -norm } else { norm };
+            let mut vtv = T::zero();
+            for i in (k + 1)..N {
+                v[i] = h.elements[i][k];
+                if i == k + 1 {
+                    v[i] = x0 - alpha;
+                }
+                vtv = vtv + v[i] * v[i];
+            }
+            let beta = (T::one() + T::one()) / vtv;
+
+            // H = P * H * P and Q = Q * P with P = I - beta * v * vᵀ
+            for j in 0..N {
+                let mut s = T::zero();
+                for i in (k + 1)..N {
+                    s = s + v[i] * h.elements[i][j];
+                }
+                s = s * beta;
+                for i in (k + 1)..N {
+                    h.elements[i][j] = h.elements[i][j] - s * v[i];
+                }
+            }
+            for target in [&mut h, &mut q] {
+                for i in 0..N {
+                    let mut s = T::zero();
+                    for j in (k + 1)..N {
+                        s = s + target.elements[i][j] * v[j];
+                    }
+                    s = s * beta;
+                    for j in (k + 1)..N {
+                        target.elements[i][j] = target.elements[i][j] - s * v[j];
+                    }
+                }
+            }
+
+            h.elements[k + 1][k] = alpha;
+            for i in (k + 2)..N {
+                h.elements[i][k] = T::zero();
+            }
+        }
+
+        Hessenberg { h, q }
+    }
+
+    /// Eigenvalues of a general real matrix, by Hessenberg reduction and the implicit
+    /// double-shift (Francis) QR algorithm.
+    pub fn eigenvalues(&self) -> Result<[Complex<T>; N], LinalgError> {
+        let mut eigenvalues = francis_qr(self.hessenberg().h)?;
+        eigenvalues.sort_unstable_by(|a, b| {
+            a.re.partial_cmp(&b.re)
+                .unwrap_or(core::cmp::Ordering::Equal)
+                .then(a.im.partial_cmp(&b.im).unwrap_or(core::cmp::Ordering::Equal))
+        });
+        Ok(eigenvalues)
+    }
+
+    /// Eigenvalues and right eigenvectors of a general real matrix.
+    ///
+    /// Eigenvectors come from inverse iteration on `A - λI`, so a defective eigenvalue
+    /// yields the same eigenvector for each of its repeats.
+    #[allow(clippy::needless_range_loop)]
+    pub fn eigen(&self) -> Result<Eigen<T, N>, LinalgError> {
+        let eigenvalues = self.eigenvalues()?;
+        let mut eigenvectors = Matrix::<Complex<T>, N, N>::zeros();
+        for (j, &lambda) in eigenvalues.iter().enumerate() {
+            let vector = inverse_iteration(self, lambda);
+            for i in 0..N {
+                eigenvectors.elements[i][j] = vector[i];
+            }
+        }
+        Ok(Eigen { eigenvalues, eigenvectors })
+    }
+}
+
+/// Eigenvalues of an upper Hessenberg matrix (the `hqr` routine of EISPACK).
+#[allow(clippy::needless_range_loop)]
+fn francis_qr<T, const N: usize>(mut h: Matrix<T, N, N>) -> Result<[Complex<T>; N], LinalgError>
+where
+    T: Float + Debug,
+{
+    let a = &mut h.elements;
+    let mut wr = [T::zero(); N];
+    let mut wi = [T::zero(); N];
+    let two = T::one() + T::one();
+
+    let mut norm = T::zero();
+    for i in 0..N {
+        for j in i.saturating_sub(1)..N {
+            norm = norm + a[i][j].abs();
+        }
+    }
+
+    let mut total_iterations = 0;
+    let mut nn = N as isize - 1;
+    let mut t = T::zero();
+    while nn >= 0 {
+        let mut iterations = 0;
+        loop {
+            let n = nn as usize;
+
+            // Look for a single small subdiagonal element to split the matrix
+            let mut l = n;
+            while l >= 1 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == T::zero() {
+                    s = norm;
+                }
+                if a[l][l - 1].abs() + s == s {
+                    a[l][l - 1] = T::zero();
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = a[n][n];
+            if l == n {
+                // One root found
+                wr[n] = x + t;
+                wi[n] = T::zero();
+                nn -= 1;
+                break;
+            }
+
+            let mut y = a[n - 1][n - 1];
+            let mut w = a[n][n - 1] * a[n - 1][n];
+            if l == n - 1 {
+                // Two roots found
+                let p = (y - x) / two;
+                let q = p * p + w;
+                let mut z = q.abs().sqrt();
+                x = x + t;
+                if q >= T::zero() {
+                    z = p + with_sign(z, p);
+                    wr[n - 1] = x + z;
+                    wr[n] = x + z;
+                    if z != T::zero() {
+                        wr[n] = x - w / z;
+                    }
+                    wi[n - 1] = T::zero();
+                    wi[n] = T::zero();
+                } else {
+                    wr[n - 1] = x + p;
+                    wr[n] = x + p;
+                    wi[n - 1] = -z;
+                    wi[n] = z;
+                }
+                nn -= 2;
+                break;
+            }
+
+            if iterations == HQR_MAX_ITERATIONS {
+                return Err(LinalgError::NoConvergence { iterations: total_iterations });
+            }
+            if iterations == 10 || iterations == 20 {
+                // Exceptional shift to break cycles
+                t = t + x;
+                for i in 0..=n {
+                    a[i][i] = a[i][i] - x;
+                }
+                let s = a[n][n - 1].abs() + a[n - 1][n - 2].abs();
+                x = T::from(0.75).unwrap() * s;
+                y = x;
+                w = T::from(-0.4375).unwrap() * s * s;
+            }
+            iterations += 1;
+            total_iterations += 1;
+
+            // Form the shift and look for two consecutive small subdiagonal elements
+            let mut m = n - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = a[m][m];
+                r = x - z;
+                let s = y - z;
+                p = (r * s - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - r - s;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == l {
+                    break;
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u + v == v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in (m + 2)..=n {
+                a[i][i - 2] = T::zero();
+                if i != m + 2 {
+                    a[i][i - 3] = T::zero();
+                }
+            }
+
+            // Double QR step on rows l..=n and columns m..=n
+            for k in m..n {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = T::zero();
+                    if k != n - 1 {
+                        r = a[k + 2][k - 1];
+                    }
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != T::zero() {
+                        p = p / x;
+                        q = q / x;
+                        r = r / x;
+                    }
+                }
+                let s = with_sign((p * p + q * q + r * r).sqrt(), p);
+                if s == T::zero() {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+                p = p + s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q = q / p;
+                r = r / p;
+
+                for j in k..=n {
+                    let mut p = a[k][j] + q * a[k + 1][j];
+                    if k != n - 1 {
+                        p = p + r * a[k + 2][j];
+                        a[k + 2][j] = a[k + 2][j] - p * z;
+                    }
+                    a[k + 1][j] = a[k + 1][j] - p * y;
+                    a[k][j] = a[k][j] - p * x;
+                }
+                for i in l..=n.min(k + 3) {
+                    let mut p = x * a[i][k] + y * a[i][k + 1];
+                    if k != n - 1 {
+                        p = p + z * a[i][k + 2];
+                        a[i][k + 2] = a[i][k + 2] - p * r;
+                    }
+                    a[i][k + 1] = a[i][k + 1] - p * q;
+                    a[i][k] = a[i][k] - p;
+                }
+            }
+        }
+    }
+
+    let mut eigenvalues = [Complex::new(T::zero(), T::zero()); N];
+    for i in 0..N {
+        eigenvalues[i] = Complex::new(wr[i], wi[i]);
+    }
+    Ok(eigenvalues)
+}
+
+/// Unit eigenvector of `a` for the (already accurate) eigenvalue `lambda`.
+#[allow(clippy::needless_range_loop)]
+fn inverse_iteration<T, const N: usize>(a: &Matrix<T, N, N>, lambda: Complex<T>) -> [Complex<T>; N]
+where
+    T: Float + Debug,
+{
+    let zero = Complex::new(T::zero(), T::zero());
+
+    let mut norm = T::zero();
+    for i in 0..N {
+        for j in 0..N {
+            norm = norm.max(a.elements[i][j].abs());
+        }
+    }
+    let tiny = (T::epsilon() * norm).max(T::min_positive_value());
+
+    // LU factorization of A - λI with partial pivoting, nudging exact zero pivots
+    let mut lu = [[zero; N]; N];
+    for i in 0..N {
+        for j in 0..N {
+            lu[i][j] = Complex::new(a.elements[i][j], T::zero());
+        }
+        lu[i][i] = lu[i][i] - lambda;
+    }
+    let mut perm = [0; N];
+    for k in 0..N {
+        let mut pivot = k;
+        for i in (k + 1)..N {
+            if lu[i][k].norm() > lu[pivot][k].norm() {
+                pivot = i;
+            }
+        }
+        lu.swap(k, pivot);
+        perm[k] = pivot;
+        if lu[k][k].norm() < tiny {
+            lu[k][k] = Complex::new(tiny, T::zero());
+        }
+        for i in (k + 1)..N {
+            let factor = lu[i][k] / lu[k][k];
+            lu[i][k] = factor;
+            for j in (k + 1)..N {
+                lu[i][j] = lu[i][j] - factor * lu[k][j];
+            }
+        }
+    }
+
+    let mut x = [Complex::new(T::one(), T::zero()); N];
+    for _ in 0..INVERSE_ITERATION_STEPS {
+        for k in 0..N {
+            x.swap(k, perm[k]);
+        }
+        for i in 0..N {
+            for k in 0..i {
+                x[i] = x[i] - lu[i][k] * x[k];
+            }
+        }
+        for i in (0..N).rev() {
+            for k in (i + 1)..N {
+                x[i] = x[i] - lu[i][k] * x[k];
+            }
+            x[i] = x[i] / lu[i][i];
+        }
+
+        // Scale by the largest component, which also makes real eigenvectors real
+        let mut largest = zero;
+        for &value in x.iter() {
+            if value.norm() > largest.norm() {
+                largest = value;
+            }
+        }
+        if largest == zero {
+            break;
+        }
+        for value in x.iter_mut() {
+            *value = *value / largest;
+        }
+    }
+
+    let mut length = T::zero();
+    for value in x.iter() {
+        length = length.hypot(value.norm());
+    }
+    if length > T::zero() {
+        for value in x.iter_mut() {
+            *value = value.unscale(length);
+        }
+    }
+    x
+}
+
+/// Singular value decomposition `A = U * Σ * Vᵀ`.
+#[derive(Debug, Clone)]
+pub struct Svd<T, const M: usize, const N: usize>
+where
+    T: Num + Copy + Debug,
+{
+    pub u: Matrix<T, M, M>,      // Left singular vectors
+    pub singular_values: [T; N], // In descending order, entries past `min(M, N)` are zero
+    pub v_t: Matrix<T, N, N>,    // Right singular vectors, transposed
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float + Debug,
+{
+    /// Full singular value decomposition by one-sided (Hestenes) Jacobi rotations.
+    pub fn svd(&self) -> Result<Svd<T, M, N>, LinalgError> {
+        if M >= N {
+            let (u, singular_values, v) = one_sided_jacobi(*self)?;
+            Ok(Svd { u, singular_values, v_t: v.transpose() })
+        } else {
+            // Aᵀ = U' * Σ' * V'ᵀ gives A = V' * Σ'ᵀ * U'ᵀ
+            let (u, values, v) = one_sided_jacobi(self.transpose())?;
+            let mut singular_values = [T::zero(); N];
+            singular_values[..M].copy_from_slice(&values);
+            Ok(Svd { u: v, singular_values, v_t: u.transpose() })
+        }
+    }
+
+    /// Number of singular values larger than `tolerance`.
+    pub fn rank(&self, tolerance: T) -> Result<usize, LinalgError> {
+        Ok(self.svd()?.rank(tolerance))
+    }
+
+    /// Ratio of the largest to the smallest singular value, in the 2-norm.
+    pub fn condition_number(&self) -> Result<T, LinalgError> {
+        Ok(self.svd()?.condition_number())
+    }
+
+    /// Induced 2-norm, the largest singular value.
+    pub fn norm_2(&self) -> Result<T, LinalgError> {
+        Ok(self.svd()?.norm_2())
+    }
+
+    /// Moore–Penrose pseudo-inverse `A⁺`, for matrices of any shape and rank.
+    ///
+    /// Singular values up to `tolerance` are treated as zero, so rank-deficient directions are
+    /// projected out instead of blowing up. [`Svd::default_tolerance`] is a sensible choice.
+    pub fn pseudo_inverse(&self, tolerance: T) -> Result<Matrix<T, N, M>, LinalgError> {
+        Ok(self.svd()?.pseudo_inverse(tolerance))
+    }
+}
+
+/// Jacobi SVD of a matrix with at least as many rows as columns.
+#[allow(clippy::type_complexity)]
+#[allow(clippy::needless_range_loop)]
+fn one_sided_jacobi<T, const R: usize, const C: usize>(
+    a: Matrix<T, R, C>,
+) -> Result<(Matrix<T, R, R>, [T; C], Matrix<T, C, C>), LinalgError>
+where
+    T: Float + Debug,
+{
+    let mut w = a;
+    let mut v = Matrix::<T, C, C>::identity();
+
+    // Rotate pairs of columns until all of them are mutually orthogonal
+    let mut converged = false;
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..C {
+            for q in (p + 1)..C {
+                let mut alpha = T::zero();
+                let mut beta = T::zero();
+                let mut gamma = T::zero();
+                for i in 0..R {
+                    alpha = alpha + w.elements[i][p] * w.elements[i][p];
+                    beta = beta + w.elements[i][q] * w.elements[i][q];
+                    gamma = gamma + w.elements[i][p] * w.elements[i][q];
+                }
+                if gamma.abs() <= T::epsilon() * alpha.sqrt() * beta.sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = zeta.signum() / (zeta.abs() + zeta.hypot(T::one()));
+                let c = T::one() / t.hypot(T::one());
+                let s = c * t;
+                for i in 0..R {
+                    let wp = w.elements[i][p];
+                    let wq = w.elements[i][q];
+                    w.elements[i][p] = c * wp - s * wq;
+                    w.elements[i][q] = s * wp + c * wq;
+                }
+                for i in 0..C {
+                    let vp = v.elements[i][p];
+                    let vq = v.elements[i][q];
+                    v.elements[i][p] = c * vp - s * vq;
+                    v.elements[i][q] = s * vp + c * vq;
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::NoConvergence { iterations: JACOBI_MAX_SWEEPS });
+    }
+
+    // The singular values are the norms of the orthogonalized columns
+    let mut singular_values = [T::zero(); C];
+    for j in 0..C {
+        for i in 0..R {
+            singular_values[j] = singular_values[j].hypot(w.elements[i][j]);
+        }
+    }
+    for i in 0..C {
+        let mut largest = i;
+        for j in (i + 1)..C {
+            if singular_values[j] > singular_values[largest] {
+                largest = j;
+            }
+        }
+        if largest != i {
+            singular_values.swap(i, largest);
+            for k in 0..R {
+                w.elements[k].swap(i, largest);
+            }
+            for k in 0..C {
+                v.elements[k].swap(i, largest);
+            }
+        }
+    }
+
+    // Normalized columns give U, numerically zero ones are completed to an orthonormal basis
+    let mut u = Matrix::<T, R, R>::zeros();
+    let largest = if C > 0 { singular_values[0] } else { T::zero() };
+    let tolerance = T::epsilon() * T::from(R).unwrap() * largest;
+    let mut filled = 0;
+    while filled < C && singular_values[filled] > tolerance {
+        for i in 0..R {
+            u.elements[i][filled] = w.elements[i][filled] / singular_values[filled];
+        }
+        filled += 1;
+    }
+    for j in filled..R {
+        let mut best = [T::zero(); R];
+        let mut best_norm = -T::one();
+        for k in 0..R {
+            let mut candidate = [T::zero(); R];
+            candidate[k] = T::one();
+            // Orthogonalize twice to keep the completed basis orthonormal to working precision
+            for _ in 0..2 {
+                for c in 0..j {
+                    let mut projection = T::zero();
+                    for i in 0..R {
+                        projection = projection + u.elements[i][c] * candidate[i];
+                    }
+                    for i in 0..R {
+                        candidate[i] = candidate[i] - projection * u.elements[i][c];
+                    }
+                }
+            }
+            let mut norm = T::zero();
+            for value in candidate.iter() {
+                norm = norm.hypot(*value);
+            }
+            if norm > best_norm {
+                best = candidate;
+                best_norm = norm;
+            }
+        }
+        for i in 0..R {
+            u.elements[i][j] = best[i] / best_norm;
+        }
+    }
+
+    Ok((u, singular_values, v))
+}
+
+impl<T, const M: usize, const N: usize> Svd<T, M, N>
+where
+    T: Float + Debug,
+{
+    /// The `M x N` rectangular diagonal matrix of singular values.
+    pub fn sigma(&self) -> Matrix<T, M, N> {
+        let mut sigma = Matrix::<T, M, N>::zeros();
+        for i in 0..M.min(N) {
+            sigma.elements[i][i] = self.singular_values[i];
+        }
+        sigma
+    }
+
+    /// Number of singular values larger than `tolerance`.
+    pub fn rank(&self, tolerance: T) -> usize {
+        self.singular_values[..M.min(N)]
+            .iter()
+            .filter(|&&value| value > tolerance)
+            .count()
+    }
+
+    /// Ratio of the largest to the smallest singular value, infinite for rank-deficient matrices.
+    pub fn condition_number(&self) -> T {
+        let count = M.min(N);
+        if count == 0 {
+            return T::zero();
+        }
+        let smallest = self.singular_values[count - 1];
+        if smallest == T::zero() {
+            return T::infinity();
+        }
+        self.singular_values[0] / smallest
+    }
+
+    /// A rank tolerance of `max(M, N) * eps * σ_max`, suitable for most inputs.
+    pub fn default_tolerance(&self) -> T {
+        T::epsilon() * T::from(M.max(N)).unwrap() * self.norm_2()
+    }
+
+    /// Induced 2-norm, the largest singular value.
+    pub fn norm_2(&self) -> T {
+        if M.min(N) == 0 {
+            return T::zero();
+        }
+        self.singular_values[0]
+    }
+
+    /// Moore–Penrose pseudo-inverse `V * Σ⁺ * Uᵀ`, dropping singular values up to `tolerance`.
+    pub fn pseudo_inverse(&self, tolerance: T) -> Matrix<T, N, M> {
+        let mut result = Matrix::<T, N, M>::zeros();
+        for k in 0..M.min(N) {
+            let value = self.singular_values[k];
+            if value <= tolerance {
+                continue;
+            }
+            for i in 0..N {
+                let scaled = self.v_t.elements[k][i] / value;
+                for j in 0..M {
+                    result.elements[i][j] = result.elements[i][j] + scaled * self.u.elements[j][k];
+                }
+            }
+        }
+        result
+    }
+}
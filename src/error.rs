@@ -0,0 +1,33 @@
+use core::fmt;
+
+/// Errors reported by the linear algebra routines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinalgError {
+    /// The matrix is singular, or too close to singular to be factorized reliably.
+    Singular,
+    /// A decomposition that requires a (semi-)definite matrix met a non-positive (or numerically zero) pivot.
+    NotPositiveDefinite,
+    /// The operands do not have the dimensions the operation requires.
+    DimensionMismatch,
+    /// An element could not be converted to the requested numeric type.
+    ConversionFailed,
+    /// An iterative algorithm did not converge within its iteration budget.
+    NoConvergence { iterations: usize },
+}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinalgError::Singular => write!(f, "matrix is singular"),
+            LinalgError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            LinalgError::DimensionMismatch => write!(f, "matrix dimensions do not match"),
+            LinalgError::ConversionFailed => write!(f, "numeric conversion failed"),
+            LinalgError::NoConvergence { iterations } => {
+                write!(f, "no convergence after {} iterations", iterations)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LinalgError {}
@@ -1,7 +1,10 @@
-use crate::Matrix;
-use num::{Num, Float};
+use crate::{ColumnVector, Matrix, Vector};
+use num::Float;
 use core::fmt::Debug;
+use core::ops::{Add, Mul, Neg, Sub};
 
+/// Coordinate axis for the elementary rotations of [`generate_rotation_3d`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Axis {
     X,
     Y,
@@ -11,7 +14,16 @@ pub enum Axis {
 // Reflections placeholder
 
 // Rotations
-pub fn generate_rotation_2d<T: Float + Debug + Copy>(angle: f32) -> Matrix<f32, 2, 2> {
+/// Counter-clockwise rotation by `angle` radians in the plane.
+///
+/// ```
+/// use numbrs::{generate_rotation_2d, vector, Matrix};
+///
+/// let r = generate_rotation_2d(core::f32::consts::FRAC_PI_2);
+/// let y = r * vector![[1.0_f32], [0.0]];
+/// assert!(y[0].abs() < 1e-6 && (y[1] - 1.0).abs() < 1e-6);
+/// ```
+pub fn generate_rotation_2d<T: Float + Debug>(angle: T) -> Matrix<T, 2, 2> {
     let cos = angle.cos();
     let sin = angle.sin();
     Matrix::new([
@@ -20,25 +32,351 @@ pub fn generate_rotation_2d<T: Float + Debug + Copy>(angle: f32) -> Matrix<f32,
     ])
 }
 
+/// Rotate `v` counter-clockwise by `angle` radians without building the matrix.
+pub fn rotate_vector_2d<T: Float + Debug>(v: ColumnVector<T, 2>, angle: T) -> ColumnVector<T, 2> {
+    let cos = angle.cos();
+    let sin = angle.sin();
+    let [[x], [y]] = v.0.elements;
+    ColumnVector::new(Matrix::new([[cos * x - sin * y], [sin * x + cos * y]]))
+}
+
+/// Angle in `(-π, π]` of a 2D rotation matrix.
+pub fn rotation_angle_2d<T: Float + Debug>(rotation: &Matrix<T, 2, 2>) -> T {
+    rotation.elements[1][0].atan2(rotation.elements[0][0])
+}
+
+/// Rotation by `first` followed by `second`, i.e. `second * first`.
+///
+/// Long chains of compositions slowly drift away from orthonormal; see
+/// [`renormalize_rotation_2d`].
+pub fn compose_rotations_2d<T: Float + Debug>(first: &Matrix<T, 2, 2>, second: &Matrix<T, 2, 2>) -> Matrix<T, 2, 2> {
+    *second * *first
+}
+
+/// Nearest exact rotation, in the Frobenius norm, to a drifted rotation matrix.
+///
+/// This is the rotational part of the polar decomposition, whose angle is
+/// `atan2(m₁₀ - m₀₁, m₀₀ + m₁₁)`.
+pub fn renormalize_rotation_2d<T: Float + Debug>(rotation: &Matrix<T, 2, 2>) -> Matrix<T, 2, 2> {
+    let [[m00, m01], [m10, m11]] = rotation.elements;
+    generate_rotation_2d((m10 - m01).atan2(m00 + m11))
+}
+
+/// Inverse of a rotation matrix, which is its transpose.
+pub fn invert_rotation_2d<T: Float + Debug>(rotation: &Matrix<T, 2, 2>) -> Matrix<T, 2, 2> {
+    rotation.transpose()
+}
+
+/// Right-handed rotation by `angle` radians about a coordinate axis.
+///
+/// ```
+/// use numbrs::{rotation_3d, vector, Axis, Matrix};
+///
+/// let r = rotation_3d!(core::f64::consts::FRAC_PI_2, Axis::Z);
+/// let y = r * vector![[1.0], [0.0], [0.0]];
+/// assert!((y[1] - 1.0).abs() < 1e-12);
+/// ```
+pub fn generate_rotation_3d<T: Float + Debug>(angle: T, axis: Axis) -> Matrix<T, 3, 3> {
+    let cos = angle.cos();
+    let sin = angle.sin();
+    let (zero, one) = (T::zero(), T::one());
+    match axis {
+        Axis::X => Matrix::new([
+            [one, zero, zero],
+            [zero, cos, -sin],
+            [zero, sin, cos],
+        ]),
+        Axis::Y => Matrix::new([
+            [cos, zero, sin],
+            [zero, one, zero],
+            [-sin, zero, cos],
+        ]),
+        Axis::Z => Matrix::new([
+            [cos, -sin, zero],
+            [sin, cos, zero],
+            [zero, zero, one],
+        ]),
+    }
+}
+
+/// Right-handed rotation by `angle` radians about an arbitrary `axis`, by Rodrigues' formula
+/// `R = I + sin(θ) K + (1 - cos(θ)) K²` with `K = [axis]ₓ`.
+///
+/// `axis` is normalized first; a zero axis produces NaN elements.
+pub fn generate_rotation_axis_angle<T: Float + Debug>(axis: ColumnVector<T, 3>, angle: T) -> Matrix<T, 3, 3> {
+    let k = axis.normalize().skew();
+    Matrix::identity() + k * angle.sin() + k * k * (T::one() - angle.cos())
+}
+
+const SLERP_LINEAR_THRESHOLD: f64 = 0.9995;
+
+/// Quaternion `w + x i + y j + z k`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion<T>
+where
+    T: Debug,
+{
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Quaternion<T>
+where
+    T: Float + Debug,
+{
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// Quaternion with real part `w` and vector part `v`.
+    pub fn from_parts(w: T, v: ColumnVector<T, 3>) -> Self {
+        let [[x], [y], [z]] = v.0.elements;
+        Self { w, x, y, z }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    /// The vector part `(x, y, z)`.
+    pub fn vector(&self) -> ColumnVector<T, 3> {
+        ColumnVector::new(Matrix::new([[self.x], [self.y], [self.z]]))
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    /// Multiplicative inverse `q* / |q|²`; the zero quaternion yields NaN components.
+    pub fn inverse(&self) -> Self {
+        self.conjugate() * (T::one() / self.norm_squared())
+    }
+
+    /// `self / |self|`; the zero quaternion yields NaN components.
+    pub fn normalize(&self) -> Self {
+        *self * (T::one() / self.norm())
+    }
+}
+
+impl<T> Add for Quaternion<T>
+where
+    T: Float + Debug,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.w + other.w, self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
 
-// pub fn generate_rotation_3d<T: Num + Debug + Copy>(angle: T, axis: Axis) -> Matrix<T, 3, 3> {
-//     let cos = angle.cos();
-//     let sin = angle.sin();
-//     match axis {
-//         Axis::X => Matrix::new([
-//             [T::one(), T::zero(), T::zero()],
-//             [T::zero(), cos, -sin],
-//             [T::zero(), sin, cos],
-//         ]),
-//         Axis::Y => Matrix::new([
-//             [cos, T::zero(), sin],
-//             [T::zero(), T::one(), T::zero()],
-//             [-sin, T::zero(), cos],
-//         ]),
-//         Axis::Z => Matrix::new([
-//             [cos, -sin, T::zero()],
-//             [sin, cos, T::zero()],
-//             [T::zero(), T::zero(), T::one()],
-//         ]),
-//     }
-// }
\ No newline at end of file
+impl<T> Sub for Quaternion<T>
+where
+    T: Float + Debug,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.w - other.w, self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T> Neg for Quaternion<T>
+where
+    T: Float + Debug,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<T> Mul<T> for Quaternion<T>
+where
+    T: Float + Debug,
+{
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        Self::new(self.w * scalar, self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+/// Hamilton product.
+impl<T> Mul for Quaternion<T>
+where
+    T: Float + Debug,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let (a, b) = (self, other);
+        Self::new(
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        )
+    }
+}
+
+/// Quaternion of unit norm, representing a 3D rotation.
+///
+/// `q` and `-q` describe the same rotation.
+///
+/// ```
+/// use numbrs::{vector, Matrix, UnitQuaternion};
+///
+/// let q = UnitQuaternion::from_axis_angle(vector![[0.0], [0.0], [1.0]], core::f64::consts::FRAC_PI_2);
+/// let y = q * vector![[1.0], [0.0], [0.0]];
+/// assert!((y[1] - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitQuaternion<T>(Quaternion<T>)
+where
+    T: Debug;
+
+impl<T> UnitQuaternion<T>
+where
+    T: Float + Debug,
+{
+    /// Normalize `q` into a unit quaternion; the zero quaternion yields NaN components.
+    pub fn new_normalize(q: Quaternion<T>) -> Self {
+        Self(q.normalize())
+    }
+
+    pub fn identity() -> Self {
+        Self(Quaternion::identity())
+    }
+
+    /// Right-handed rotation by `angle` radians about `axis`, which is normalized first.
+    pub fn from_axis_angle(axis: ColumnVector<T, 3>, angle: T) -> Self {
+        let half = angle / T::from(2).unwrap();
+        Self(Quaternion::from_parts(half.cos(), axis.normalize() * half.sin()))
+    }
+
+    pub fn quaternion(&self) -> Quaternion<T> {
+        self.0
+    }
+
+    /// Rotation angle in `[0, π]`.
+    pub fn angle(&self) -> T {
+        T::from(2).unwrap() * self.0.vector().norm().atan2(self.0.w.abs())
+    }
+
+    /// The inverse rotation; for a unit quaternion this is the conjugate.
+    pub fn inverse(&self) -> Self {
+        Self(self.0.conjugate())
+    }
+
+    /// Restore unit norm after accumulated rounding, e.g. from many integration steps.
+    pub fn renormalize(&self) -> Self {
+        Self::new_normalize(self.0)
+    }
+
+    /// Rotate `v`, computing `q v q*` without forming the full products.
+    pub fn rotate(&self, v: ColumnVector<T, 3>) -> ColumnVector<T, 3> {
+        let u = self.0.vector();
+        let t = u.cross(&v) * T::from(2).unwrap();
+        v + t * self.0.w + u.cross(&t)
+    }
+
+    pub fn to_rotation_matrix(&self) -> Matrix<T, 3, 3> {
+        let Quaternion { w, x, y, z } = self.0;
+        let one = T::one();
+        let two = T::from(2).unwrap();
+        Matrix::new([
+            [one - two * (y * y + z * z), two * (x * y - w * z), two * (x * z + w * y)],
+            [two * (x * y + w * z), one - two * (x * x + z * z), two * (y * z - w * x)],
+            [two * (x * z - w * y), two * (y * z + w * x), one - two * (x * x + y * y)],
+        ])
+    }
+
+    /// Quaternion of a rotation matrix, by Shepperd's method.
+    ///
+    /// The largest of `w, x, y, z` is recovered first, which keeps the divisions well
+    /// conditioned for any rotation angle. The result is normalized, so a slightly
+    /// non-orthogonal input still gives a unit quaternion.
+    pub fn from_rotation_matrix(rotation: &Matrix<T, 3, 3>) -> Self {
+        let m = &rotation.elements;
+        let one = T::one();
+        let two = T::from(2).unwrap();
+        let quarter = T::from(0.25).unwrap();
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        let q = if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Quaternion::new(quarter * s, (m[2][1] - m[1][2]) / s, (m[0][2] - m[2][0]) / s, (m[1][0] - m[0][1]) / s)
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+            Quaternion::new((m[2][1] - m[1][2]) / s, quarter * s, (m[0][1] + m[1][0]) / s, (m[0][2] + m[2][0]) / s)
+        } else if m[1][1] > m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+            Quaternion::new((m[0][2] - m[2][0]) / s, (m[0][1] + m[1][0]) / s, quarter * s, (m[1][2] + m[2][1]) / s)
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+            Quaternion::new((m[1][0] - m[0][1]) / s, (m[0][2] + m[2][0]) / s, (m[1][2] + m[2][1]) / s, quarter * s)
+        };
+        Self::new_normalize(q)
+    }
+
+    /// Spherical linear interpolation, `self` at `t = 0` and `other` at `t = 1`.
+    ///
+    /// Follows the shorter arc. Nearly identical rotations fall back to normalized linear
+    /// interpolation, where the spherical formula would divide by `sin(θ) ≈ 0`.
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let mut end = other.0;
+        let mut cos_theta = self.0.dot(&end);
+        if cos_theta < T::zero() {
+            end = -end;
+            cos_theta = -cos_theta;
+        }
+
+        if cos_theta > T::from(SLERP_LINEAR_THRESHOLD).unwrap() {
+            return Self::new_normalize(self.0 + (end - self.0) * t);
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let start_weight = ((T::one() - t) * theta).sin() / sin_theta;
+        let end_weight = (t * theta).sin() / sin_theta;
+        Self::new_normalize(self.0 * start_weight + end * end_weight)
+    }
+}
+
+impl<T> Mul for UnitQuaternion<T>
+where
+    T: Float + Debug,
+{
+    type Output = Self;
+
+    /// Composition: `(a * b).rotate(v) == a.rotate(b.rotate(v))`.
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+impl<T> Mul<ColumnVector<T, 3>> for UnitQuaternion<T>
+where
+    T: Float + Debug,
+{
+    type Output = ColumnVector<T, 3>;
+
+    fn mul(self, v: ColumnVector<T, 3>) -> ColumnVector<T, 3> {
+        self.rotate(v)
+    }
+}
@@ -4,11 +4,26 @@
 mod geometry;
 mod matrix;
 mod decomposition;
+mod error;
+mod vector;
+mod view;
 
 // pub use complex::Complex;
 pub use num::Complex;
-pub use matrix::{ColumnVector, Matrix, RowVector};
-pub use decomposition::{PartialPivLu};
+pub use matrix::{ColumnVector, Matrix, RowVector, SquareMatrix};
+pub use decomposition::{
+    Cholesky, ColPivQr, Determinant, Eigen, Hessenberg, Ldlt, PartialPivLu, Qr, Svd,
+    SymmetricEigen,
+};
+pub use error::LinalgError;
+pub use geometry::{
+    compose_rotations_2d, generate_rotation_2d, generate_rotation_3d, generate_rotation_axis_angle,
+    invert_rotation_2d, renormalize_rotation_2d, rotate_vector_2d, rotation_angle_2d, Axis, Quaternion, UnitQuaternion,
+};
+pub use vector::Vector;
+pub use view::{MatrixView, MatrixViewMut};
+#[doc(hidden)]
+pub use view::{assemble_blocks, BlockRow, BlockRows};
 // #[macro_export]
 // macro_rules! vector {
 //     ($value:expr; $size:expr) => {
@@ -102,6 +117,41 @@ macro_rules! matrix {
     }};
 }
 
+/// Assemble a matrix from rows of blocks, e.g. `block_matrix![[a, b], [c, d]]`.
+///
+/// The result type has to be known from context. Blocks in a row must share
+/// a height and every row must span the full width; a layout that does not
+/// tile the result is a compile error.
+///
+/// ```
+/// use numbrs::{block_matrix, matrix, Matrix};
+///
+/// let a = matrix![[1, 2], [3, 4]];
+/// let m: Matrix<i32, 3, 3> = block_matrix![[a, matrix![[5], [6]]], [matrix![[7, 8, 9]]]];
+/// assert_eq!(m, matrix![[1, 2, 5], [3, 4, 6], [7, 8, 9]]);
+/// ```
+///
+/// ```compile_fail,E0080
+/// use numbrs::{block_matrix, matrix, Matrix};
+///
+/// // The second row is one column short
+/// let m: Matrix<i32, 2, 3> = block_matrix![[matrix![[1, 2]], matrix![[3]]], [matrix![[4, 5]]]];
+/// ```
+#[macro_export]
+macro_rules! block_matrix {
+    (@row) => { () };
+    (@row $head:expr $(, $tail:expr)*) => {
+        ($head, $crate::block_matrix!(@row $($tail),*))
+    };
+    (@rows) => { () };
+    (@rows [$($head:expr),+] $(, [$($tail:expr),+])*) => {
+        ($crate::block_matrix!(@row $($head),+), $crate::block_matrix!(@rows $([$($tail),+]),*))
+    };
+    ($([$($block:expr),+ $(,)?]),+ $(,)?) => {
+        $crate::assemble_blocks($crate::block_matrix!(@rows $([$($block),+]),+))
+    };
+}
+
 #[macro_export]
 macro_rules! identity_matrix {
     ($type:ty, $size:expr) => {{
@@ -116,9 +166,7 @@ macro_rules! identity_matrix {
 #[macro_export]
 macro_rules! rotation_2d {
     ($angle:expr) => {{
-        let cos = $angle.cos();
-        let sin = $angle.sin();
-        $crate::Matrix::new([[cos, -sin], [sin, cos]])
+        $crate::generate_rotation_2d($angle)
     }};
 }
 
@@ -1,10 +1,10 @@
-use num::{Num, Signed, ToPrimitive};
+use crate::decomposition::abs;
+use crate::{LinalgError, Vector};
+use num::{Float, Num, ToPrimitive};
 use core::fmt::Debug;
-use core::ops::{Add, BitXor, Mul, Neg, Sub};
-
-#[cfg(not(feature = "std"))]
-use num_traits::Float;
+use core::ops::{Add, BitXor, Index, IndexMut, Mul, Neg, Sub};
 
+const POWER_ITERATION_MAX_ITERATIONS: usize = 100;
 
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub struct Matrix<T, const M: usize, const N: usize>
@@ -14,6 +14,34 @@ where
     pub elements: [[T; N]; M],
 }
 
+/// A matrix with as many rows as columns.
+///
+/// Square-only operations such as `determinant`, `inverse` and the decompositions are
+/// implemented on `Matrix<T, N, N>` only, so calling them on a rectangular matrix is a
+/// compile error rather than a runtime check:
+///
+/// ```compile_fail,E0599
+/// use numbrs::{matrix, Matrix};
+///
+/// let a = matrix![[1, 2, 3], [4, 5, 6]];
+/// a.determinant();
+/// ```
+///
+/// ```compile_fail,E0599
+/// use numbrs::{matrix, Matrix};
+///
+/// let a = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+/// a.inverse();
+/// ```
+///
+/// ```
+/// use numbrs::{matrix, Matrix, SquareMatrix};
+///
+/// let a: SquareMatrix<f64, 2> = matrix![[4.0, 7.0], [2.0, 6.0]];
+/// assert_eq!(a.lu_decomposition().determinant(), 10.0);
+/// ```
+pub type SquareMatrix<T, const N: usize> = Matrix<T, N, N>;
+
 impl<T, const M: usize, const N: usize> Matrix<T, M, N>
 where
     T: Debug + Num + Copy,
@@ -34,59 +62,6 @@ where
         DynMatrix::new(M, N, data)
     }
 
-    #[cfg(feature = "std")]
-    pub fn determinant(&self) -> T 
-    where T: Neg<Output = T>{
-        assert_eq!(M, N, "Matrix must be square to compute determinant.");
-        let dyn_mat = self.to_dyn();
-        dyn_mat.determinant()
-    }
-
-    #[cfg(not(feature = "std"))]
-    pub fn determinant(&self) -> T
-    where T: Neg<Output = T> + Signed + PartialOrd,
-    {
-        assert_eq!(M, N, "Matrix must be square to compute determinant.");
-
-        match M {
-            1 => self.elements[0][0],
-            2 => self.determinant_2x2(),
-            3 => self.determinant_3x3(),
-            _ => {
-                let mut mat = self.elements;
-                let mut sign = T::one();
-
-                for k in 0..N {
-                    let mut pivot = k;
-                    for i in (k + 1)..M {
-                        if mat[i][k].abs() > mat[pivot][k].abs() {
-                            pivot = i;
-                        }
-                    }
-                    if pivot != k {
-                        mat.swap(k, pivot);
-                        sign = -sign;
-                    }
-                    if mat[k][k] == T::zero() {
-                        return T::zero();
-                    }
-                    for i in (k + 1)..M {
-                        let factor = mat[i][k] / mat[k][k];
-                        for j in k..N {
-                            mat[i][j] = mat[i][j] - factor * mat[k][j];
-                        }
-                    }
-                }
-
-                let mut det = sign;
-                for i in 0..M {
-                    det = det * mat[i][i];
-                }
-                det
-            }
-        }
-    }
-
     pub fn zeros() -> Self {
         Self {
             elements: [[T::zero(); N]; M],
@@ -105,14 +80,16 @@ where
         }
     }
 
+    #[allow(clippy::needless_range_loop)]
     pub fn identity() -> Self {
         let mut elements = [[T::zero(); N]; M];
-        for i in 0..M {
+        for i in 0..M.min(N) {
             elements[i][i] = T::one();
         }
         Self { elements }
     }
 
+    #[allow(clippy::needless_range_loop)]
     pub fn transpose(self) -> Matrix<T, N, M> {
         let mut elements = [[T::zero(); M]; N];
         for i in 0..M {
@@ -123,197 +100,382 @@ where
         Matrix::new(elements)
     }
 
-    #[cfg(feature = "std")]
-    pub fn inverse(self) -> Option<Matrix<f64, M, N>>
+    // pub fn conjugate_transpose(self) -> Matrix<T, N, M> {
+    //     let transposed = self.transpose();
+    //     // for each element do the conjugate, if the element is complex
+    //     let mut result = [[T::zero(); M]; N];
+
+    //     for i in 0..N {
+    //         for j in 0..M {
+    //             result[i][j] = if let Some(conjugate) = transposed.elements[i][j].try_conjugate() {
+    //                 conjugate
+    //             } else {
+    //                 transposed.elements[i][j] // No conjugation for real numbers
+    //             };
+    //         }
+    //     }
+    
+    //     Matrix::new(result)
+    // }
+
+    pub fn nrows(&self) -> usize {
+        M
+    }
+
+    pub fn ncols(&self) -> usize {
+        N
+    }
+
+    pub fn len(&self) -> usize {
+        M * N
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.elements.get(row)?.get(col)
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.elements.get_mut(row)?.get_mut(col)
+    }
+
+    /// Iterate over the elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.elements.iter().flatten()
+    }
+
+    /// Iterate mutably over the elements in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.elements.iter_mut().flatten()
+    }
+
+    /// Apply `f` to every element, producing a matrix of the same shape.
+    ///
+    /// ```
+    /// use numbrs::{matrix, Matrix};
+    ///
+    /// let m = matrix![[1, -2], [-3, 4]];
+    /// assert_eq!(m.map(|x| x * x), matrix![[1, 4], [9, 16]]);
+    /// assert_eq!(m.map(|x| x > 0).elements, [[true, false], [false, true]]);
+    /// ```
+    pub fn map<U, F>(self, mut f: F) -> Matrix<U, M, N>
+    where
+        U: Debug,
+        F: FnMut(T) -> U,
+    {
+        Matrix {
+            elements: self.elements.map(|row| row.map(&mut f)),
+        }
+    }
+
+    /// Combine corresponding elements of `self` and `other` with `f`.
+    pub fn zip_map<U, V, F>(self, other: &Matrix<U, M, N>, mut f: F) -> Matrix<V, M, N>
     where
-        T: Num + Copy + ToPrimitive + PartialOrd + Debug,
+        U: Debug + Copy,
+        V: Debug,
+        F: FnMut(T, U) -> V,
     {
-        let precision = 6;
-        // Ensure the matrix is square
-        if M != N {
-            return None;
+        Matrix {
+            elements: core::array::from_fn(|i| {
+                core::array::from_fn(|j| f(self.elements[i][j], other.elements[i][j]))
+            }),
         }
+    }
 
-        let aug_cols = 2 * N; // Calculate augmented matrix columns at runtime
+    /// Elementwise (Hadamard) product.
+    pub fn component_mul(self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a * b)
+    }
 
-        // Create an augmented matrix [A | I] as f64
-        let mut augmented = vec![vec![0.0_f64; aug_cols]; M];
+    /// Elementwise quotient, with the usual division-by-zero behaviour of `T`.
+    pub fn component_div(self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    /// Kronecker product; the output dimensions must be `M * P` by `N * Q`.
+    ///
+    /// ```
+    /// use numbrs::{matrix, Matrix};
+    ///
+    /// let a = matrix![[1, 2]];
+    /// let b = matrix![[1, 0], [0, 1]];
+    /// let k: Matrix<i32, 2, 4> = a.kron(&b);
+    /// assert_eq!(k, matrix![[1, 0, 2, 0], [0, 1, 0, 2]]);
+    /// ```
+    pub fn kron<const P: usize, const Q: usize, const R: usize, const S: usize>(
+        &self,
+        other: &Matrix<T, P, Q>,
+    ) -> Matrix<T, R, S> {
+        const { assert!(R == M * P && S == N * Q, "Output must be (M * P) x (N * Q)") };
+        let mut elements = [[T::zero(); S]; R];
         for i in 0..M {
             for j in 0..N {
-                augmented[i][j] = self.elements[i][j].to_f64().expect("Conversion to f64 failed");
-                augmented[i][j + N] = if i == j { 1.0 } else { 0.0 };
+                for k in 0..P {
+                    for l in 0..Q {
+                        elements[i * P + k][j * Q + l] = self.elements[i][j] * other.elements[k][l];
+                    }
+                }
             }
         }
+        Matrix::new(elements)
+    }
 
-        // Perform row operations to transform the left side into the identity matrix
-        for i in 0..N {
-            // Find the pivot
-            let mut pivot = i;
-            for j in i + 1..M {
-                if augmented[j][i].abs() > augmented[pivot][i].abs() {
-                    pivot = j;
+    /// Place `other` to the right of `self`; `K` must equal `N + P`.
+    ///
+    /// ```
+    /// use numbrs::{matrix, Matrix};
+    ///
+    /// let ab: Matrix<i32, 2, 3> = matrix![[1], [2]].hstack(matrix![[3, 4], [5, 6]]);
+    /// assert_eq!(ab, matrix![[1, 3, 4], [2, 5, 6]]);
+    /// ```
+    pub fn hstack<const P: usize, const K: usize>(self, other: Matrix<T, M, P>) -> Matrix<T, M, K> {
+        const { assert!(N + P == K, "Output must have as many columns as both inputs") };
+        let mut result = Matrix::zeros();
+        result.set_block(0, 0, self);
+        result.set_block(0, N, other);
+        result
+    }
+
+    /// Place `other` below `self`; `K` must equal `M + P`.
+    pub fn vstack<const P: usize, const K: usize>(self, other: Matrix<T, P, N>) -> Matrix<T, K, N> {
+        const { assert!(M + P == K, "Output must have as many rows as both inputs") };
+        let mut result = Matrix::zeros();
+        result.set_block(0, 0, self);
+        result.set_block(M, 0, other);
+        result
+    }
+
+    /// Accumulate the elements in row-major order.
+    pub fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.elements.into_iter().flatten().fold(init, f)
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    /// Sum of all elements.
+    pub fn sum(&self) -> T {
+        self.fold(T::zero(), |acc, x| acc + x)
+    }
+
+    /// Product of all elements.
+    pub fn product(&self) -> T {
+        self.fold(T::one(), |acc, x| acc * x)
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Debug + Num + Copy,
+{
+    /// Sum of the diagonal elements.
+    pub fn trace(&self) -> T {
+        (0..N).fold(T::zero(), |acc, i| acc + self.elements[i][i])
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Debug + Num + Copy + PartialOrd,
+{
+    /// First element, in row-major order, for which no other element is `better`.
+    ///
+    /// Incomparable elements such as NaN are skipped unless every element is one.
+    fn extremum(&self, better: impl Fn(&T, &T) -> bool) -> Option<((usize, usize), T)> {
+        let mut best: Option<((usize, usize), T)> = None;
+        for i in 0..M {
+            for j in 0..N {
+                let x = self.elements[i][j];
+                let replace = match best {
+                    None => true,
+                    Some((_, current)) => current.partial_cmp(&current).is_none() || better(&x, &current),
+                };
+                if replace {
+                    best = Some(((i, j), x));
                 }
             }
+        }
+        best
+    }
 
-            // Swap the rows
-            augmented.swap(i, pivot);
+    /// Smallest element, or `None` if the matrix is empty.
+    pub fn min(&self) -> Option<T> {
+        self.extremum(|a, b| a < b).map(|(_, x)| x)
+    }
 
-            // Ensure the pivot is not zero
-            if augmented[i][i] == 0.0 {
-                return None;
-            }
+    /// Largest element, or `None` if the matrix is empty.
+    pub fn max(&self) -> Option<T> {
+        self.extremum(|a, b| a > b).map(|(_, x)| x)
+    }
 
-            // Scale the row to make the pivot 1
-            let divisor = augmented[i][i];
-            for j in 0..aug_cols {
-                augmented[i][j] /= divisor;
-            }
+    /// `(row, col)` of the first smallest element.
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        self.extremum(|a, b| a < b).map(|(index, _)| index)
+    }
 
-            // Perform row operations to make the other elements in the column zero
-            for j in 0..M {
-                if i != j {
-                    let factor = augmented[j][i];
-                    for k in 0..aug_cols {
-                        augmented[j][k] -= factor * augmented[i][k];
-                    }
-                }
+    /// `(row, col)` of the first largest element.
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        self.extremum(|a, b| a > b).map(|(index, _)| index)
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Debug + Num + Copy + PartialOrd + Neg<Output = T>,
+{
+    /// Largest absolute column sum, the norm induced by the vector 1-norm.
+    pub fn norm_1(&self) -> T {
+        let mut norm = T::zero();
+        for j in 0..N {
+            let sum = (0..M).fold(T::zero(), |acc, i| acc + abs(self.elements[i][j]));
+            if sum > norm {
+                norm = sum;
             }
         }
+        norm
+    }
 
-        // Extract the right side of the augmented matrix and round the results
-        let mut result = [[0.0; N]; M];
+    /// Largest absolute row sum, the norm induced by the vector infinity-norm.
+    pub fn norm_inf(&self) -> T {
+        let mut norm = T::zero();
         for i in 0..M {
-            for j in 0..N {
-                result[i][j] = (augmented[i][j + N] * 10f64.powi(precision as i32)).round()
-                    / 10f64.powi(precision as i32);
+            let sum = (0..N).fold(T::zero(), |acc, j| acc + abs(self.elements[i][j]));
+            if sum > norm {
+                norm = sum;
             }
         }
+        norm
+    }
 
-        Some(Matrix::new(result))
-    }
-
-    fn determinant_2x2(&self) -> T {
-        assert!(M == 2 && N == 2, "Matrix must be 2x2 for this function.");
-        self.elements[0][0] * self.elements[1][1] - self.elements[0][1] * self.elements[1][0]
-    }
-
-    /// Determinant for 3x3 matrix
-    fn determinant_3x3(&self) -> T {
-        assert!(M == 3 && N == 3, "Matrix must be 3x3 for this function.");
-        let a = self.elements[0][0];
-        let b = self.elements[0][1];
-        let c = self.elements[0][2];
-        let d = self.elements[1][0];
-        let e = self.elements[1][1];
-        let f = self.elements[1][2];
-        let g = self.elements[2][0];
-        let h = self.elements[2][1];
-        let i = self.elements[2][2];
-
-        a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g)
-    }
-
-    /// Determinant for NxN matrix
-    // pub fn determinant(&self) -> T {
-    //     assert!(M == N, "Matrix must be square to compute determinant.");
-
-    //     match M {
-    //         1 => self.elements[0][0],
-    //         2 => self.determinant_2x2(),
-    //         3 => self.determinant_3x3(),
-    //         _ => {
-    //             let mut det = T::zero();
-    //             for i in 0..N {
-    //                 let sign = if i % 2 == 0 { T::one() } else { -T::one() };
-    //                 let minor = self.minor(0, i);
-    //                 det = det + sign * self.elements[0][i] * minor.determinant();
-    //             }
-    //             det
-    //         }
-    //     }
-    // }
+    /// Largest absolute value of any element.
+    pub fn max_abs(&self) -> T {
+        self.fold(T::zero(), |norm, x| if abs(x) > norm { abs(x) } else { norm })
+    }
+}
 
-    /// Compute the minor matrix by removing the specified row and column
-    // fn minor(&self, row: usize, col: usize) -> Matrix<T, M, N> {
-    //     assert!(M > 1 && N > 1, "Matrix must be larger than 1x1 to compute a minor.");
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float + Debug,
+{
+    /// Square root of the sum of squared elements.
+    pub fn frobenius_norm(&self) -> T {
+        self.fold(T::zero(), |acc, x| acc + x * x).sqrt()
+    }
 
-    //     let mut minor_elements = vec![vec![T::zero(); N - 1]; M - 1];
-    //     for i in 0..M {
-    //         if i == row {
-    //             continue;
-    //         }
-    //         for j in 0..N {
-    //             if j == col {
-    //                 continue;
-    //             }
-    //             let minor_row = if i < row { i } else { i - 1 };
-    //             let minor_col = if j < col { j } else { j - 1 };
-    //             minor_elements[minor_row][minor_col] = self.elements[i][j];
-    //         }
-    //     }
+    /// Estimate of the induced 2-norm by power iteration on `AᵀA`, without computing an SVD.
+    ///
+    /// Iterates until successive estimates agree to within `tolerance` relative to the
+    /// estimate, or for at most 100 steps. The result never exceeds the true 2-norm;
+    /// use [`Matrix::norm_2`] when the exact value is needed.
+    #[allow(clippy::needless_range_loop)]
+    pub fn norm_2_estimate(&self, tolerance: T) -> T {
+        // Work on A / max|aᵢⱼ| so that squaring entries of AᵀAx cannot overflow
+        let max_abs = self.max_abs();
+        if max_abs == T::zero() {
+            return T::zero();
+        }
+        let a = self.map(|value| value / max_abs);
 
-    //     let minor_elements: [[T; N - 1]; M - 1] = minor_elements.into_iter().map(|row| row.try_into().unwrap()).collect::<Vec<_>>().try_into().unwrap();
-    //     Matrix::new(minor_elements)
-    // }
+        // Start from the largest row, which A maps to a non-zero vector
+        let mut start = 0;
+        let mut largest = T::zero();
+        for i in 0..M {
+            let norm = a.elements[i].iter().fold(T::zero(), |acc, &x| acc + x * x);
+            if norm > largest {
+                largest = norm;
+                start = i;
+            }
+        }
 
+        let scale = largest.sqrt();
+        let mut x = a.elements[start].map(|value| value / scale);
+        let mut estimate = T::zero();
+        for _ in 0..POWER_ITERATION_MAX_ITERATIONS {
+            let mut ax = [T::zero(); M];
+            for i in 0..M {
+                for j in 0..N {
+                    ax[i] = ax[i] + a.elements[i][j] * x[j];
+                }
+            }
+            let ax_norm = ax.iter().fold(T::zero(), |acc, &v| acc + v * v).sqrt();
 
-    // pub fn determinant(self) -> T
-    // where
-    //     T: Num + Copy + Signed,
-    // {
-    //     // Ensure the matrix is square
-    //     assert_eq!(M, N, "Matrix must be square");
+            // ⟨x, AᵀAx⟩ = |Ax|² > 0, so AᵀAx is non-zero as long as Ax is
+            let mut y = [T::zero(); N];
+            for j in 0..N {
+                for i in 0..M {
+                    y[j] = y[j] + a.elements[i][j] * ax[i];
+                }
+            }
+            let y_norm = y.iter().fold(T::zero(), |acc, &v| acc + v * v).sqrt();
 
-    //     // Base case: 1x1 matrix
-    //     if M == 1 {
-    //         return self.elements[0][0];
-    //     }
+            let previous = estimate;
+            estimate = y_norm / ax_norm;
+            x = y.map(|value| value / y_norm);
+            if (estimate - previous).abs() <= tolerance * estimate {
+                break;
+            }
+        }
+        estimate * max_abs
+    }
+}
 
-    //     // Base case: 2x2 matrix
-    //     if M == 2 {
-    //         return self.elements[0][0] * self.elements[1][1] - self.elements[0][1] * self.elements[1][0];
-    //     }
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Debug + Num + Copy + ToPrimitive,
+{
+    /// Copy of row `i` as a row vector.
+    pub fn row(&self, i: usize) -> RowVector<T, N> {
+        RowVector::new(Matrix::new([self.elements[i]]))
+    }
 
-    //     // Recursive case: use Laplace expansion
-    //     let mut det = T::zero();
-    //     for i in 0..N {
-    //         let sign = if i % 2 == 0 { T::one() } else { -T::one() };
-    //         let minor = self.minor(0, i);
-    //         det = det + sign * self.elements[0][i] * minor.determinant();
-    //     }
+    /// Copy of column `j` as a column vector.
+    #[allow(clippy::needless_range_loop)]
+    pub fn column(&self, j: usize) -> ColumnVector<T, M> {
+        let mut elements = [[T::zero(); 1]; M];
+        for i in 0..M {
+            elements[i][0] = self.elements[i][j];
+        }
+        ColumnVector::new(Matrix::new(elements))
+    }
 
-    //     det
-    // }
+    /// Iterate over the rows as row vectors.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = RowVector<T, N>> + DoubleEndedIterator + '_ {
+        (0..M).map(move |i| self.row(i))
+    }
 
-    // pub fn conjugate_transpose(self) -> Matrix<T, N, M> {
-    //     let transposed = self.transpose();
-    //     // for each element do the conjugate, if the element is complex
-    //     let mut result = [[T::zero(); M]; N];
+    /// Iterate over the columns as column vectors.
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = ColumnVector<T, M>> + DoubleEndedIterator + '_ {
+        (0..N).map(move |j| self.column(j))
+    }
+}
 
-    //     for i in 0..N {
-    //         for j in 0..M {
-    //             result[i][j] = if let Some(conjugate) = transposed.elements[i][j].try_conjugate() {
-    //                 conjugate
-    //             } else {
-    //                 transposed.elements[i][j] // No conjugation for real numbers
-    //             };
-    //         }
-    //     }
-    
-    //     Matrix::new(result)
-    // }
+impl<T, const M: usize, const N: usize> Index<(usize, usize)> for Matrix<T, M, N>
+where
+    T: Debug,
+{
+    type Output = T;
 
-    pub fn len(&self) -> usize {
-        M * N
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.elements[row][col]
     }
-
-    
 }
 
-#[derive(Debug)]
-pub struct NumWrapper<T: Num>(T)
+impl<T, const M: usize, const N: usize> IndexMut<(usize, usize)> for Matrix<T, M, N>
 where
-    T: Debug + Num + Copy;
+    T: Debug,
+{
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.elements[row][col]
+    }
+}
 
 #[derive(Debug)]
 pub struct RowVector<T, const N: usize>(pub Matrix<T, 1, N>)
@@ -328,40 +490,64 @@ where
         RowVector(matrix)
     }
 
-    pub fn magnitude(&self) -> f64 {
-        self.0.elements[0]
-            .iter()
-            .map(|&x| {
-                let x_f64 = x.to_f64().expect("Conversion to f64 failed");
-                x_f64 * x_f64
-            })
-            .sum::<f64>()
-            .sqrt()
+    /// Euclidean norm computed in `f64`, for element types without a `Float` implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element cannot be converted to `f64`, see [`RowVector::try_magnitude_f64`].
+    pub fn magnitude_f64(&self) -> f64 {
+        self.try_magnitude_f64().expect("Conversion to f64 failed")
     }
 
-    pub fn dot(&self, other: &RowVector<T, N>) -> f64 {
+    pub fn try_magnitude_f64(&self) -> Result<f64, LinalgError> {
+        Ok(self.try_dot_f64(self)?.sqrt())
+    }
+
+    /// Dot product computed in `f64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element cannot be converted to `f64`, see [`RowVector::try_dot_f64`].
+    pub fn dot_f64(&self, other: &RowVector<T, N>) -> f64 {
+        self.try_dot_f64(other).expect("Conversion to f64 failed")
+    }
+
+    pub fn try_dot_f64(&self, other: &RowVector<T, N>) -> Result<f64, LinalgError> {
         self.0.elements[0]
             .iter()
             .zip(other.0.elements[0].iter())
             .map(|(&a, &b)| {
-                let a_f64 = a.to_f64().expect("Conversion to f64 failed");
-                let b_f64 = b.to_f64().expect("Conversion to f64 failed");
-                a_f64 * b_f64
+                let a_f64 = a.to_f64().ok_or(LinalgError::ConversionFailed)?;
+                let b_f64 = b.to_f64().ok_or(LinalgError::ConversionFailed)?;
+                Ok(a_f64 * b_f64)
             })
             .sum()
     }
 
-    pub fn angle_between(&self, other: &RowVector<T, N>) -> f64 {
-        let dot_product = self.dot(other);
-        let magnitudes = self.magnitude() * other.magnitude();
+    /// Angle in radians computed in `f64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element cannot be converted to `f64`, see [`RowVector::try_angle_between_f64`].
+    pub fn angle_between_f64(&self, other: &RowVector<T, N>) -> f64 {
+        self.try_angle_between_f64(other).expect("Conversion to f64 failed")
+    }
+
+    pub fn try_angle_between_f64(&self, other: &RowVector<T, N>) -> Result<f64, LinalgError> {
+        let dot_product = self.try_dot_f64(other)?;
+        let magnitudes = self.try_magnitude_f64()? * other.try_magnitude_f64()?;
         let cos_theta = dot_product / magnitudes;
-        cos_theta.acos()
+        Ok(cos_theta.clamp(-1.0, 1.0).acos())
     }
 
+    /// Outer product `selfᵀ other`, pairing every element of `self` with every element of `other`.
+    pub fn outer<const K: usize>(&self, other: &RowVector<T, K>) -> Matrix<T, N, K> {
+        ColumnVector(self.0.transpose()).outer(&ColumnVector(other.0.transpose()))
+    }
 }
 
 #[derive(Debug)]
-pub struct ColumnVector<T, const N: usize>(Matrix<T, N, 1>)
+pub struct ColumnVector<T, const N: usize>(pub Matrix<T, N, 1>)
 where
     T: Debug + Num + Copy + ToPrimitive;
 
@@ -373,15 +559,91 @@ where
         ColumnVector(matrix)
     }
 
-    pub fn magnitude(&self) -> f64 {
-        self.0.elements[0]
+    /// Euclidean norm computed in `f64`, for element types without a `Float` implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element cannot be converted to `f64`, see [`ColumnVector::try_magnitude_f64`].
+    pub fn magnitude_f64(&self) -> f64 {
+        self.try_magnitude_f64().expect("Conversion to f64 failed")
+    }
+
+    pub fn try_magnitude_f64(&self) -> Result<f64, LinalgError> {
+        Ok(self.try_dot_f64(self)?.sqrt())
+    }
+
+    /// Dot product computed in `f64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element cannot be converted to `f64`, see [`ColumnVector::try_dot_f64`].
+    pub fn dot_f64(&self, other: &ColumnVector<T, M>) -> f64 {
+        self.try_dot_f64(other).expect("Conversion to f64 failed")
+    }
+
+    pub fn try_dot_f64(&self, other: &ColumnVector<T, M>) -> Result<f64, LinalgError> {
+        self.0
+            .elements
             .iter()
-            .map(|&x| {
-                let x_f64 = x.to_f64().expect("Conversion to f64 failed");
-                x_f64 * x_f64
+            .zip(other.0.elements.iter())
+            .map(|([a], [b])| {
+                let a_f64 = a.to_f64().ok_or(LinalgError::ConversionFailed)?;
+                let b_f64 = b.to_f64().ok_or(LinalgError::ConversionFailed)?;
+                Ok(a_f64 * b_f64)
             })
-            .sum::<f64>()
-            .sqrt()
+            .sum()
+    }
+
+    /// Outer product `self otherᵀ`.
+    #[allow(clippy::needless_range_loop)]
+    pub fn outer<const K: usize>(&self, other: &ColumnVector<T, K>) -> Matrix<T, M, K> {
+        let mut elements = [[T::zero(); K]; M];
+        for i in 0..M {
+            for j in 0..K {
+                elements[i][j] = self.0.elements[i][0] * other.0.elements[j][0];
+            }
+        }
+        Matrix::new(elements)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for RowVector<T, N>
+where
+    T: Debug + Num + Copy + ToPrimitive,
+{
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self.0.elements[0][i]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for RowVector<T, N>
+where
+    T: Debug + Num + Copy + ToPrimitive,
+{
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.0.elements[0][i]
+    }
+}
+
+impl<T, const N: usize> Index<usize> for ColumnVector<T, N>
+where
+    T: Debug + Num + Copy + ToPrimitive,
+{
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self.0.elements[i][0]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for ColumnVector<T, N>
+where
+    T: Debug + Num + Copy + ToPrimitive,
+{
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.0.elements[i][0]
     }
 }
 
@@ -450,38 +712,7 @@ where
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        // Ensure the matrices have the same dimensions
-        assert_eq!(
-            self.len(),
-            other.len(),
-            "Matrices must have the same dimensions"
-        );
-
-        let mut elements = [[T::zero(); N]; M];
-        for i in 0..M {
-            for j in 0..N {
-                elements[i][j] = self.elements[i][j] + other.elements[i][j];
-            }
-        }
-
-        // let elements = self
-        //     .elements
-        //     .iter()
-        //     .zip(other.elements.iter())
-        //     .map(|(row_a, row_b)| {
-        //         row_a
-        //             .iter()
-        //             .zip(row_b.iter())
-        //             .map(|(a, b)| *a + *b)
-        //             .collect::<Vec<_>>()
-        //             .try_into()
-        //             .expect("Row size mismatch")
-        //     })
-        //     .collect::<Vec<_>>()
-        //     .try_into()
-        //     .expect("Matrix size mismatch");
-
-        Self::new(elements)
+        self.zip_map(&other, |a, b| a + b)
     }
 }
 
@@ -540,38 +771,7 @@ where
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        // Ensure the matrices have the same dimensions
-        assert_eq!(
-            self.len(),
-            other.len(),
-            "Matrices must have the same dimensions"
-        );
-
-        // let elements = self
-        //     .elements
-        //     .iter()
-        //     .zip(other.elements.iter())
-        //     .map(|(row_a, row_b)| {
-        //         row_a
-        //             .iter()
-        //             .zip(row_b.iter())
-        //             .map(|(a, b)| *a - *b)
-        //             .collect::<Vec<_>>()
-        //             .try_into()
-        //             .expect("Row size mismatch")
-        //     })
-        //     .collect::<Vec<_>>()
-        //     .try_into()
-        //     .expect("Matrix size mismatch");
-
-        let mut elements = [[T::zero(); N]; M];
-        for i in 0..M {
-            for j in 0..N {
-                elements[i][j] = self.elements[i][j] - other.elements[i][j];
-            }
-        }
-
-        Self::new(elements)
+        self.zip_map(&other, |a, b| a - b)
     }
 }
 
@@ -606,27 +806,7 @@ where
 
     fn mul(self, scalar: U) -> Self {
         let scalar_t = T::from(scalar);
-        // let elements = self
-        //     .elements
-        //     .iter()
-        //     .map(|row| {
-        //         row.iter()
-        //             .map(|&x| x * scalar_t)
-        //             .collect::<Vec<_>>()
-        //             .try_into()
-        //             .expect("Row size mismatch")
-        //     })
-        //     .collect::<Vec<_>>()
-        //     .try_into()
-        //     .expect("Matrix size mismatch");
-
-        let mut elements = [[T::zero(); N]; M];
-        for i in 0..M {
-            for j in 0..N {
-                elements[i][j] = self.elements[i][j] * scalar_t;
-            }
-        }
-        Self::new(elements)
+        self.map(|x| x * scalar_t)
     }
 }
 
@@ -637,6 +817,7 @@ where
 {
     type Output = Matrix<T, M, P>;
 
+    #[allow(clippy::needless_range_loop)]
     fn mul(self, other: Matrix<T, N, P>) -> Self::Output {
         // Ensure the matrices can be multiplied
         let mut result = [[T::zero(); P]; M];
@@ -659,6 +840,7 @@ where
 {
     type Output = ColumnVector<T, M>;
 
+    #[allow(clippy::needless_range_loop)]
     fn mul(self, vector: ColumnVector<T, N>) -> Self::Output {
         let mut result = [[T::zero(); 1]; M]; // M rows, 1 column
 
@@ -678,6 +860,7 @@ where
 {
     type Output = Matrix<T, 1, N>; // Result is a row vector
 
+    #[allow(clippy::needless_range_loop)]
     fn mul(self, matrix: Matrix<T, M, N>) -> Self::Output {
         let mut result = [[T::zero(); N]; 1]; // 1 row, N columns
 
@@ -695,18 +878,10 @@ impl<T, const N: usize> Mul<RowVector<T, N>> for RowVector<T, N>
 where
     T: Debug + Num + Copy + ToPrimitive,
 {
-    type Output = f64;
+    type Output = T;
 
-    fn mul(self, other: RowVector<T, N>) -> f64 {
-        self.0.elements[0]
-            .iter()
-            .zip(other.0.elements[0].iter())
-            .map(|(&a, &b)| {
-                let a_f64 = a.to_f64().expect("Conversion to f64 failed");
-                let b_f64 = b.to_f64().expect("Conversion to f64 failed");
-                a_f64 * b_f64
-            })
-            .sum()
+    fn mul(self, other: RowVector<T, N>) -> T {
+        self.dot(&other)
     }
 }
 
@@ -714,19 +889,10 @@ impl<T, const N: usize> Mul<ColumnVector<T, N>> for ColumnVector<T, N>
 where
     T: Debug + Num + Copy + ToPrimitive,
 {
-    type Output = f64;
+    type Output = T;
 
-    fn mul(self, other: ColumnVector<T, N>) -> f64 {
-        self.0
-            .elements
-            .iter()
-            .zip(other.0.elements.iter())
-            .map(|([a], [b])| {
-                let a_f64 = a.to_f64().expect("Conversion to f64 failed");
-                let b_f64 = b.to_f64().expect("Conversion to f64 failed");
-                a_f64 * b_f64
-            })
-            .sum()
+    fn mul(self, other: ColumnVector<T, N>) -> T {
+        self.dot(&other)
     }
 }
 
@@ -761,15 +927,7 @@ where
     type Output = Matrix<T, N, M>; // Result is a matrix
 
     fn mul(self, other: RowVector<T, M>) -> Self::Output {
-        let mut result = [[T::zero(); M]; N]; // Create a zero-initialized matrix of size N x M
-
-        for i in 0..N {
-            for j in 0..M {
-                result[i][j] = self.0.elements[i][0] * other.0.elements[0][j];
-            }
-        }
-
-        Matrix::new(result)
+        self.outer(&ColumnVector(other.0.transpose()))
     }
 }
 
@@ -830,9 +988,9 @@ where
         // Repeated squaring algorithm
         while exp > 0 {
             if exp % 2 == 1 {
-                result = result * base.clone(); // Multiply when the current bit is set
+                result = result * base; // Multiply when the current bit is set
             }
-            base = base.clone() * base; // Square the base
+            base = base * base; // Square the base
             exp /= 2;
         }
 
@@ -872,29 +1030,32 @@ impl<T> DynMatrix<T>
 where
     T: Debug + Num + Copy + Neg<Output = T>,
 {
+    /// # Panics
+    ///
+    /// Panics if `data` does not hold `rows * cols` elements, see [`DynMatrix::try_new`].
     pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Self {
-        assert_eq!(data.len(), rows * cols, "Data does not match dimensions");
-        DynMatrix { rows, cols, data }
+        Self::try_new(rows, cols, data).expect("Data does not match dimensions")
     }
 
-    pub fn determinant(&self) -> T {
-        assert_eq!(self.rows, self.cols, "Matrix must be square to compute determinant.");
-        if self.rows == 1 {
-            return self.data[0];
-        }
-        if self.rows == 2 {
-            return self.data[0] * self.data[3] - self.data[1] * self.data[2];
-        }
-        let mut det = T::zero();
-        for col in 0..self.cols {
-            let sign = if col % 2 == 0 { T::one() } else { -T::one() };
-            let element = self.data[col];
-            let minor_matrix = self.minor(0, col);
-            det = det + sign * element * minor_matrix.determinant();
+    pub fn try_new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, LinalgError> {
+        if data.len() != rows * cols {
+            return Err(LinalgError::DimensionMismatch);
         }
-        det
+        Ok(DynMatrix { rows, cols, data })
     }
+
+    /// # Panics
+    ///
+    /// Panics if `row` or `col` is out of range, see [`DynMatrix::try_minor`].
     pub fn minor(&self, row: usize, col: usize) -> DynMatrix<T> {
+        self.try_minor(row, col).expect("Row or column is out of range")
+    }
+
+    /// The matrix with row `row` and column `col` removed.
+    pub fn try_minor(&self, row: usize, col: usize) -> Result<DynMatrix<T>, LinalgError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(LinalgError::DimensionMismatch);
+        }
         let new_rows = self.rows - 1;
         let new_cols = self.cols - 1;
         let mut new_data = Vec::with_capacity(new_rows * new_cols);
@@ -905,6 +1066,28 @@ where
                 new_data.push(self.data[i * self.cols + j]);
             }
         }
-        DynMatrix { rows: new_rows, cols: new_cols, data: new_data }
+        Ok(DynMatrix { rows: new_rows, cols: new_cols, data: new_data })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> DynMatrix<T>
+where
+    T: crate::Determinant,
+{
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, see [`DynMatrix::try_determinant`].
+    pub fn determinant(&self) -> T {
+        self.try_determinant().expect("Matrix must be square to compute determinant.")
+    }
+
+    /// Determinant by the same algorithm as [`Matrix::determinant`] for the element type.
+    pub fn try_determinant(&self) -> Result<T, LinalgError> {
+        if self.rows != self.cols {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        let mut data = self.data.clone();
+        Ok(T::determinant_of_rows(&mut data, self.rows))
     }
 }
\ No newline at end of file
@@ -0,0 +1,207 @@
+use crate::{ColumnVector, Matrix, RowVector};
+use num::{Float, Num, One, ToPrimitive};
+use core::fmt::Debug;
+use core::ops::Neg;
+
+/// Operations shared by [`RowVector`] and [`ColumnVector`].
+///
+/// Implementors only provide `dot`, `map` and `zip_map`; everything else is derived from them.
+///
+/// ```
+/// use numbrs::{vector, Matrix, Vector};
+///
+/// let position = vector![[3.0], [4.0]];
+/// assert_eq!(position.norm(), 5.0);
+/// assert_eq!(position.lerp(&vector![[5.0], [4.0]], 0.5), vector![[4.0], [4.0]]);
+/// ```
+pub trait Vector: Copy {
+    type Scalar: Debug + Num + Copy;
+
+    fn dot(&self, other: &Self) -> Self::Scalar;
+
+    /// Apply `f` to every component.
+    fn map(self, f: impl FnMut(Self::Scalar) -> Self::Scalar) -> Self;
+
+    /// Combine corresponding components of `self` and `other` with `f`.
+    fn zip_map(self, other: &Self, f: impl FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar) -> Self;
+
+    fn norm_squared(&self) -> Self::Scalar {
+        self.dot(self)
+    }
+
+    /// Euclidean length.
+    fn norm(&self) -> Self::Scalar
+    where
+        Self::Scalar: Float,
+    {
+        self.norm_squared().sqrt()
+    }
+
+    /// Same as [`Vector::norm`].
+    fn magnitude(&self) -> Self::Scalar
+    where
+        Self::Scalar: Float,
+    {
+        self.norm()
+    }
+
+    /// Unit vector in the direction of `self`; a zero vector yields NaN components.
+    fn normalize(&self) -> Self
+    where
+        Self::Scalar: Float,
+    {
+        let norm = self.norm();
+        self.map(|x| x / norm)
+    }
+
+    /// Euclidean distance between the two points.
+    fn distance(&self, other: &Self) -> Self::Scalar
+    where
+        Self::Scalar: Float,
+    {
+        self.zip_map(other, |a, b| a - b).norm()
+    }
+
+    /// Angle in radians between `self` and `other`.
+    fn angle_between(&self, other: &Self) -> Self::Scalar
+    where
+        Self::Scalar: Float,
+    {
+        let one = Self::Scalar::one();
+        let cos_theta = self.dot(other) / (self.norm() * other.norm());
+        // Rounding can push the cosine of (anti)parallel vectors just outside [-1, 1];
+        // `clamp` keeps the NaN from a zero-length vector
+        cos_theta.clamp(-one, one).acos()
+    }
+
+    /// Component of `self` along `onto`; projecting onto a zero vector yields NaN components.
+    fn project_onto(&self, onto: &Self) -> Self
+    where
+        Self::Scalar: Float,
+    {
+        let scale = self.dot(onto) / onto.norm_squared();
+        onto.map(|x| x * scale)
+    }
+
+    /// Linear interpolation, `self` at `t = 0` and `other` at `t = 1`.
+    fn lerp(&self, other: &Self, t: Self::Scalar) -> Self {
+        self.zip_map(other, |a, b| a + (b - a) * t)
+    }
+}
+
+impl<T, const N: usize> Vector for RowVector<T, N>
+where
+    T: Debug + Num + Copy + ToPrimitive,
+{
+    type Scalar = T;
+
+    fn dot(&self, other: &Self) -> T {
+        (0..N).fold(T::zero(), |acc, i| acc + self.0.elements[0][i] * other.0.elements[0][i])
+    }
+
+    fn map(self, f: impl FnMut(T) -> T) -> Self {
+        RowVector(self.0.map(f))
+    }
+
+    fn zip_map(self, other: &Self, f: impl FnMut(T, T) -> T) -> Self {
+        RowVector(self.0.zip_map(&other.0, f))
+    }
+}
+
+impl<T, const N: usize> Vector for ColumnVector<T, N>
+where
+    T: Debug + Num + Copy + ToPrimitive,
+{
+    type Scalar = T;
+
+    fn dot(&self, other: &Self) -> T {
+        (0..N).fold(T::zero(), |acc, i| acc + self.0.elements[i][0] * other.0.elements[i][0])
+    }
+
+    fn map(self, f: impl FnMut(T) -> T) -> Self {
+        ColumnVector(self.0.map(f))
+    }
+
+    fn zip_map(self, other: &Self, f: impl FnMut(T, T) -> T) -> Self {
+        ColumnVector(self.0.zip_map(&other.0, f))
+    }
+}
+
+impl<T> ColumnVector<T, 3>
+where
+    T: Debug + Num + Copy + ToPrimitive,
+{
+    pub fn cross(&self, other: &Self) -> Self {
+        let [[a1], [a2], [a3]] = self.0.elements;
+        let [[b1], [b2], [b3]] = other.0.elements;
+        ColumnVector(Matrix::new([[a2 * b3 - a3 * b2], [a3 * b1 - a1 * b3], [a1 * b2 - a2 * b1]]))
+    }
+
+    /// The skew-symmetric matrix `[v]ₓ`, for which `[v]ₓ * w == v.cross(&w)`.
+    pub fn skew(&self) -> Matrix<T, 3, 3>
+    where
+        T: Neg<Output = T>,
+    {
+        Matrix::from_skew(*self)
+    }
+
+    /// Scalar triple product `self · (b × c)`, the signed volume spanned by the three vectors.
+    pub fn triple_product(&self, b: &Self, c: &Self) -> T {
+        self.dot(&b.cross(c))
+    }
+}
+
+impl<T> RowVector<T, 3>
+where
+    T: Debug + Num + Copy + ToPrimitive,
+{
+    pub fn cross(&self, other: &Self) -> Self {
+        let [[a1, a2, a3]] = self.0.elements;
+        let [[b1, b2, b3]] = other.0.elements;
+        RowVector(Matrix::new([[a2 * b3 - a3 * b2, a3 * b1 - a1 * b3, a1 * b2 - a2 * b1]]))
+    }
+
+    /// The skew-symmetric matrix `[v]ₓ`, for which `[v]ₓ * wᵀ == (v × w)ᵀ`.
+    pub fn skew(&self) -> Matrix<T, 3, 3>
+    where
+        T: Neg<Output = T>,
+    {
+        Matrix::from_skew(ColumnVector(self.0.transpose()))
+    }
+
+    /// Scalar triple product `self · (b × c)`, the signed volume spanned by the three vectors.
+    pub fn triple_product(&self, b: &Self, c: &Self) -> T {
+        self.dot(&b.cross(c))
+    }
+}
+
+impl<T> Matrix<T, 3, 3>
+where
+    T: Debug + Num + Copy + ToPrimitive,
+{
+    /// Build the skew-symmetric cross-product matrix `[v]ₓ`.
+    ///
+    /// ```
+    /// use numbrs::{matrix, vector, Matrix};
+    ///
+    /// let v = vector![[1], [2], [3]];
+    /// assert_eq!(Matrix::from_skew(v), matrix![[0, -3, 2], [3, 0, -1], [-2, 1, 0]]);
+    /// ```
+    pub fn from_skew(v: ColumnVector<T, 3>) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        let zero = T::zero();
+        let [[x], [y], [z]] = v.0.elements;
+        Matrix::new([[zero, -z, y], [z, zero, -x], [-y, x, zero]])
+    }
+
+    /// Inverse of [`Matrix::from_skew`], reading `v` back from a skew-symmetric matrix.
+    ///
+    /// Only one entry of each off-diagonal pair is read, so the result is meaningful only
+    /// when `self` is skew-symmetric.
+    pub fn vee(&self) -> ColumnVector<T, 3> {
+        let m = &self.elements;
+        ColumnVector(Matrix::new([[m[2][1]], [m[0][2]], [m[1][0]]]))
+    }
+}
@@ -0,0 +1,450 @@
+use crate::{LinalgError, Matrix};
+use num::Num;
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, Sub, SubAssign};
+
+/// Borrowed `R x C` block of an `M x N` matrix, starting at (`row`, `col`).
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T, const R: usize, const C: usize, const M: usize, const N: usize>
+where
+    T: Debug,
+{
+    matrix: &'a Matrix<T, M, N>,
+    row: usize,
+    col: usize,
+}
+
+/// Mutably borrowed `R x C` block of an `M x N` matrix, starting at (`row`, `col`).
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T, const R: usize, const C: usize, const M: usize, const N: usize>
+where
+    T: Debug,
+{
+    matrix: &'a mut Matrix<T, M, N>,
+    row: usize,
+    col: usize,
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    /// Returns `true` if an `R x C` block starting at (`row`, `col`) fits in the matrix.
+    ///
+    /// A block larger than the matrix itself is rejected at compile time.
+    fn block_fits<const R: usize, const C: usize>(row: usize, col: usize) -> bool {
+        const { assert!(R <= M && C <= N, "Block is larger than the matrix") };
+        row <= M - R && col <= N - C
+    }
+
+    /// Borrow the `R x C` block starting at (`row`, `col`).
+    ///
+    /// ```
+    /// use numbrs::{matrix, Matrix};
+    ///
+    /// let a = matrix![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.fixed_view::<2, 2>(0, 1) * matrix![[1], [1]], matrix![[5], [11]]);
+    /// ```
+    ///
+    /// A block that cannot fit at any offset does not compile:
+    ///
+    /// ```compile_fail,E0080
+    /// use numbrs::{matrix, Matrix};
+    ///
+    /// let a = matrix![[1, 2, 3], [4, 5, 6]];
+    /// let view = a.fixed_view::<3, 1>(0, 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit, see [`Matrix::try_fixed_view`].
+    pub fn fixed_view<const R: usize, const C: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> MatrixView<'_, T, R, C, M, N> {
+        self.try_fixed_view(row, col).expect("Block is out of bounds")
+    }
+
+    pub fn try_fixed_view<const R: usize, const C: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> Result<MatrixView<'_, T, R, C, M, N>, LinalgError> {
+        if !Self::block_fits::<R, C>(row, col) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(MatrixView { matrix: self, row, col })
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the block does not fit, see [`Matrix::try_fixed_view_mut`].
+    pub fn fixed_view_mut<const R: usize, const C: usize>(
+        &mut self,
+        row: usize,
+        col: usize,
+    ) -> MatrixViewMut<'_, T, R, C, M, N> {
+        self.try_fixed_view_mut(row, col).expect("Block is out of bounds")
+    }
+
+    pub fn try_fixed_view_mut<const R: usize, const C: usize>(
+        &mut self,
+        row: usize,
+        col: usize,
+    ) -> Result<MatrixViewMut<'_, T, R, C, M, N>, LinalgError> {
+        if !Self::block_fits::<R, C>(row, col) {
+            return Err(LinalgError::DimensionMismatch);
+        }
+        Ok(MatrixViewMut { matrix: self, row, col })
+    }
+
+    /// Copy of the `R x C` block starting at (`row`, `col`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit, see [`Matrix::try_fixed_block`].
+    pub fn fixed_block<const R: usize, const C: usize>(&self, row: usize, col: usize) -> Matrix<T, R, C> {
+        self.fixed_view::<R, C>(row, col).to_matrix()
+    }
+
+    pub fn try_fixed_block<const R: usize, const C: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> Result<Matrix<T, R, C>, LinalgError> {
+        Ok(self.try_fixed_view::<R, C>(row, col)?.to_matrix())
+    }
+
+    /// Overwrite the block starting at (`row`, `col`) with `block`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block does not fit, see [`Matrix::try_set_block`].
+    pub fn set_block<const R: usize, const C: usize>(&mut self, row: usize, col: usize, block: Matrix<T, R, C>) {
+        self.fixed_view_mut::<R, C>(row, col).copy_from(block);
+    }
+
+    pub fn try_set_block<const R: usize, const C: usize>(
+        &mut self,
+        row: usize,
+        col: usize,
+        block: Matrix<T, R, C>,
+    ) -> Result<(), LinalgError> {
+        self.try_fixed_view_mut::<R, C>(row, col)?.copy_from(block);
+        Ok(())
+    }
+}
+
+/// One row of blocks for [`block_matrix!`](crate::block_matrix), as a nested tuple
+/// `(a, (b, (c, ())))` so that its shape is known at compile time.
+#[doc(hidden)]
+pub trait BlockRow<T>
+where
+    T: Debug + Num + Copy,
+{
+    const LEN: usize;
+    const HEIGHT: usize;
+    const WIDTH: usize;
+    /// Every block in the row has the same height.
+    const UNIFORM: bool;
+
+    fn place<const M: usize, const N: usize>(self, result: &mut Matrix<T, M, N>, row: usize, col: usize);
+}
+
+impl<T> BlockRow<T> for ()
+where
+    T: Debug + Num + Copy,
+{
+    const LEN: usize = 0;
+    const HEIGHT: usize = 0;
+    const WIDTH: usize = 0;
+    const UNIFORM: bool = true;
+
+    fn place<const M: usize, const N: usize>(self, _: &mut Matrix<T, M, N>, _: usize, _: usize) {}
+}
+
+impl<T, const R: usize, const C: usize, Rest> BlockRow<T> for (Matrix<T, R, C>, Rest)
+where
+    T: Debug + Num + Copy,
+    Rest: BlockRow<T>,
+{
+    const LEN: usize = 1 + Rest::LEN;
+    const HEIGHT: usize = R;
+    const WIDTH: usize = C + Rest::WIDTH;
+    const UNIFORM: bool = Rest::UNIFORM && (Rest::LEN == 0 || Rest::HEIGHT == R);
+
+    fn place<const M: usize, const N: usize>(self, result: &mut Matrix<T, M, N>, row: usize, col: usize) {
+        result.set_block(row, col, self.0);
+        self.1.place(result, row, col + C);
+    }
+}
+
+/// The rows of a [`block_matrix!`](crate::block_matrix), nested like [`BlockRow`].
+#[doc(hidden)]
+pub trait BlockRows<T>
+where
+    T: Debug + Num + Copy,
+{
+    const HEIGHT: usize;
+
+    fn place<const M: usize, const N: usize>(self, result: &mut Matrix<T, M, N>, row: usize);
+}
+
+impl<T> BlockRows<T> for ()
+where
+    T: Debug + Num + Copy,
+{
+    const HEIGHT: usize = 0;
+
+    fn place<const M: usize, const N: usize>(self, _: &mut Matrix<T, M, N>, _: usize) {}
+}
+
+impl<T, Row, Rest> BlockRows<T> for (Row, Rest)
+where
+    T: Debug + Num + Copy,
+    Row: BlockRow<T>,
+    Rest: BlockRows<T>,
+{
+    const HEIGHT: usize = Row::HEIGHT + Rest::HEIGHT;
+
+    fn place<const M: usize, const N: usize>(self, result: &mut Matrix<T, M, N>, row: usize) {
+        const { assert!(Row::UNIFORM, "Blocks in a row must have the same height") };
+        const { assert!(Row::WIDTH == N, "Each block row must span every column") };
+        self.0.place(result, row, 0);
+        self.1.place(result, row + Row::HEIGHT);
+    }
+}
+
+/// Backs [`block_matrix!`](crate::block_matrix); the layout is checked at compile time.
+#[doc(hidden)]
+pub fn assemble_blocks<T, B, const M: usize, const N: usize>(rows: B) -> Matrix<T, M, N>
+where
+    T: Debug + Num + Copy,
+    B: BlockRows<T>,
+{
+    const { assert!(B::HEIGHT == M, "Block rows must span every row") };
+    let mut result = Matrix::zeros();
+    rows.place(&mut result, 0);
+    result
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> MatrixView<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_matrix(&self) -> Matrix<T, R, C> {
+        let mut elements = [[T::zero(); C]; R];
+        for i in 0..R {
+            for j in 0..C {
+                elements[i][j] = self.matrix.elements[self.row + i][self.col + j];
+            }
+        }
+        Matrix::new(elements)
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> MatrixViewMut<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    pub fn to_matrix(&self) -> Matrix<T, R, C> {
+        self.as_view().to_matrix()
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, T, R, C, M, N> {
+        MatrixView { matrix: self.matrix, row: self.row, col: self.col }
+    }
+
+    pub fn copy_from(&mut self, block: Matrix<T, R, C>) {
+        for i in 0..R {
+            for j in 0..C {
+                self.matrix.elements[self.row + i][self.col + j] = block.elements[i][j];
+            }
+        }
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.copy_from(Matrix::filled(value));
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> Index<(usize, usize)>
+    for MatrixView<'_, T, R, C, M, N>
+where
+    T: Debug,
+{
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < R && col < C, "Index is outside of the view");
+        &self.matrix.elements[self.row + row][self.col + col]
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> Index<(usize, usize)>
+    for MatrixViewMut<'_, T, R, C, M, N>
+where
+    T: Debug,
+{
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < R && col < C, "Index is outside of the view");
+        &self.matrix.elements[self.row + row][self.col + col]
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> IndexMut<(usize, usize)>
+    for MatrixViewMut<'_, T, R, C, M, N>
+where
+    T: Debug,
+{
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(row < R && col < C, "Index is outside of the view");
+        &mut self.matrix.elements[self.row + row][self.col + col]
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> AddAssign<Matrix<T, R, C>>
+    for MatrixViewMut<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    fn add_assign(&mut self, other: Matrix<T, R, C>) {
+        let sum = self.to_matrix() + other;
+        self.copy_from(sum);
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> SubAssign<Matrix<T, R, C>>
+    for MatrixViewMut<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    fn sub_assign(&mut self, other: Matrix<T, R, C>) {
+        let difference = self.to_matrix() - other;
+        self.copy_from(difference);
+    }
+}
+
+// Arithmetic on views copies the block out and reuses the `Matrix` operators
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> Add<Matrix<T, R, C>>
+    for MatrixView<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    type Output = Matrix<T, R, C>;
+
+    fn add(self, other: Matrix<T, R, C>) -> Self::Output {
+        self.to_matrix() + other
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> Add<MatrixView<'_, T, R, C, M, N>>
+    for Matrix<T, R, C>
+where
+    T: Debug + Num + Copy,
+{
+    type Output = Matrix<T, R, C>;
+
+    fn add(self, other: MatrixView<'_, T, R, C, M, N>) -> Self::Output {
+        self + other.to_matrix()
+    }
+}
+
+impl<'b, T, const R: usize, const C: usize, const M: usize, const N: usize, const P: usize, const Q: usize>
+    Add<MatrixView<'b, T, R, C, P, Q>> for MatrixView<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    type Output = Matrix<T, R, C>;
+
+    fn add(self, other: MatrixView<'b, T, R, C, P, Q>) -> Self::Output {
+        self.to_matrix() + other.to_matrix()
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> Sub<Matrix<T, R, C>>
+    for MatrixView<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    type Output = Matrix<T, R, C>;
+
+    fn sub(self, other: Matrix<T, R, C>) -> Self::Output {
+        self.to_matrix() - other
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> Sub<MatrixView<'_, T, R, C, M, N>>
+    for Matrix<T, R, C>
+where
+    T: Debug + Num + Copy,
+{
+    type Output = Matrix<T, R, C>;
+
+    fn sub(self, other: MatrixView<'_, T, R, C, M, N>) -> Self::Output {
+        self - other.to_matrix()
+    }
+}
+
+impl<'b, T, const R: usize, const C: usize, const M: usize, const N: usize, const P: usize, const Q: usize>
+    Sub<MatrixView<'b, T, R, C, P, Q>> for MatrixView<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    type Output = Matrix<T, R, C>;
+
+    fn sub(self, other: MatrixView<'b, T, R, C, P, Q>) -> Self::Output {
+        self.to_matrix() - other.to_matrix()
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize, const P: usize> Mul<Matrix<T, C, P>>
+    for MatrixView<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    type Output = Matrix<T, R, P>;
+
+    fn mul(self, other: Matrix<T, C, P>) -> Self::Output {
+        self.to_matrix() * other
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize, const P: usize>
+    Mul<MatrixView<'_, T, R, C, M, N>> for Matrix<T, P, R>
+where
+    T: Debug + Num + Copy,
+{
+    type Output = Matrix<T, P, C>;
+
+    fn mul(self, other: MatrixView<'_, T, R, C, M, N>) -> Self::Output {
+        self * other.to_matrix()
+    }
+}
+
+impl<'b, T, const R: usize, const C: usize, const M: usize, const N: usize, const P: usize, const Q: usize, const K: usize>
+    Mul<MatrixView<'b, T, C, K, P, Q>> for MatrixView<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    type Output = Matrix<T, R, K>;
+
+    fn mul(self, other: MatrixView<'b, T, C, K, P, Q>) -> Self::Output {
+        self.to_matrix() * other.to_matrix()
+    }
+}
+
+impl<T, const R: usize, const C: usize, const M: usize, const N: usize> PartialEq<Matrix<T, R, C>>
+    for MatrixView<'_, T, R, C, M, N>
+where
+    T: Debug + Num + Copy,
+{
+    fn eq(&self, other: &Matrix<T, R, C>) -> bool {
+        self.to_matrix() == *other
+    }
+}
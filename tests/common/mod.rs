@@ -0,0 +1,15 @@
+use numbrs::Matrix;
+
+/// Assert that two matrices agree element-wise to within `tolerance`.
+pub fn assert_approx_eq<const M: usize, const N: usize>(a: Matrix<f64, M, N>, b: Matrix<f64, M, N>, tolerance: f64) {
+    for i in 0..M {
+        for j in 0..N {
+            assert!(
+                (a.elements[i][j] - b.elements[i][j]).abs() < tolerance,
+                "{:?} != {:?}",
+                a,
+                b
+            );
+        }
+    }
+}
@@ -0,0 +1,316 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::assert_approx_eq;
+    use numbrs::{matrix, vector, Complex, LinalgError, Matrix};
+
+    #[test]
+    fn test_cholesky() {
+        let a = matrix![[4.0_f64, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]];
+        let chol = a.cholesky().expect("Matrix is positive definite");
+        assert_eq!(chol.l, matrix![[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+        assert_eq!(chol.l * chol.l.transpose(), a);
+
+        assert!((chol.determinant() - 36.0).abs() < 1e-9);
+        assert!((chol.log_determinant() - 36.0_f64.ln()).abs() < 1e-12);
+
+        let x = chol.solve(vector![[-40.0], [-111.0], [223.0]]);
+        assert_approx_eq(x.0, matrix![[1.0], [-1.0], [2.0]], 1e-9);
+        assert_approx_eq(chol.inverse() * a, Matrix::identity(), 1e-9);
+
+        let indefinite = matrix![[1.0, 2.0], [2.0, 1.0]];
+        assert_eq!(indefinite.cholesky().err(), Some(LinalgError::NotPositiveDefinite));
+    }
+
+    #[test]
+    fn test_ldlt() {
+        let a = matrix![[4.0_f64, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]];
+        let ldlt = a.ldlt().expect("Matrix is positive definite");
+        assert_eq!(ldlt.d, [4.0, 1.0, 9.0]);
+        assert_eq!(ldlt.l, matrix![[1.0, 0.0, 0.0], [3.0, 1.0, 0.0], [-4.0, 5.0, 1.0]]);
+        assert!(ldlt.is_positive_definite());
+        assert!((ldlt.determinant() - 36.0).abs() < 1e-9);
+        let x = ldlt.solve(vector![[-40.0], [-111.0], [223.0]]).expect("Matrix is not singular");
+        assert_approx_eq(x.0, matrix![[1.0], [-1.0], [2.0]], 1e-9);
+
+        // Rank one, positive semi-definite
+        let a = matrix![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [3.0, 6.0, 9.0]];
+        assert_eq!(a.cholesky().err(), Some(LinalgError::NotPositiveDefinite));
+        let ldlt = a.ldlt().expect("Matrix is positive semi-definite");
+        assert_eq!(ldlt.d, [1.0, 0.0, 0.0]);
+        assert!(!ldlt.is_positive_definite());
+        let d = Matrix::new([[1.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+        assert_approx_eq(ldlt.l * d * ldlt.l.transpose(), a, 1e-9);
+        assert_eq!(
+            ldlt.solve(vector![[1.0], [2.0], [3.0]]).err(),
+            Some(LinalgError::Singular)
+        );
+
+        let indefinite = matrix![[1.0, 2.0], [2.0, 1.0]];
+        assert_eq!(indefinite.ldlt().err(), Some(LinalgError::NotPositiveDefinite));
+    }
+
+    #[test]
+    fn test_qr() {
+        let a = matrix![[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0], [1.0, 2.0, 3.0]];
+        let qr = a.qr();
+        let q = qr.q();
+        let r = qr.r();
+
+        assert_approx_eq(q.transpose() * q, Matrix::identity(), 1e-9);
+        assert_approx_eq(q * r, a, 1e-9);
+        assert_approx_eq(qr.thin_q() * qr.thin_r(), a, 1e-9);
+        assert_approx_eq(qr.thin_q().transpose() * qr.thin_q(), Matrix::identity(), 1e-9);
+        for i in 0..4 {
+            for j in 0..i.min(3) {
+                assert_eq!(r.elements[i][j], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_least_squares() {
+        // Best straight line C + D * t through (0, 6), (1, 0), (2, 0)
+        let a = matrix![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]];
+        let b = vector![[6.0], [0.0], [0.0]];
+        let x = a.qr().solve_least_squares(b).expect("Matrix has full column rank");
+        assert_approx_eq(x.0, matrix![[5.0], [-3.0]], 1e-9);
+
+        let rank_deficient = matrix![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+        assert_eq!(
+            rank_deficient.qr().solve_least_squares(b).err(),
+            Some(LinalgError::Singular)
+        );
+
+        let wide = matrix![[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]];
+        assert_eq!(wide.try_qr().err(), Some(LinalgError::DimensionMismatch));
+        assert_eq!(wide.try_col_piv_qr().err(), Some(LinalgError::DimensionMismatch));
+        assert!(wide.transpose().try_qr().is_ok());
+    }
+
+    #[test]
+    fn test_col_piv_qr() {
+        // The third column is twice the second, as with collinear anchors
+        let a = matrix![[1.0, 1.0, 2.0], [1.0, 2.0, 4.0], [1.0, 3.0, 6.0], [1.0, 4.0, 8.0]];
+        let qr = a.col_piv_qr();
+        assert_eq!(qr.perm[0], 2);
+        assert_approx_eq(a * qr.p(), qr.q() * qr.r(), 1e-9);
+        assert_approx_eq(a * qr.p(), qr.thin_q() * qr.thin_r(), 1e-9);
+        assert_eq!(qr.rank(qr.default_tolerance()), 2);
+
+        // A consistent right-hand side is reproduced exactly by the basic solution
+        let b = vector![[3.0], [5.0], [7.0], [9.0]];
+        let x = qr.solve(b, qr.default_tolerance());
+        assert_eq!(x.0.elements.iter().filter(|[v]| *v == 0.0).count(), 1);
+        assert_approx_eq((a * x).0, b.0, 1e-9);
+
+        let full_rank = matrix![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]];
+        let qr = full_rank.col_piv_qr();
+        assert_eq!(qr.rank(qr.default_tolerance()), 2);
+        let x = qr.solve(vector![[6.0], [0.0], [0.0]], qr.default_tolerance());
+        assert_approx_eq(x.0, matrix![[5.0], [-3.0]], 1e-9);
+    }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let a = matrix![[2.0_f64, 1.0], [1.0, 2.0]];
+        let eigen = a.symmetric_eigen().expect("Jacobi converges");
+        assert!((eigen.eigenvalues[0] - 1.0).abs() < 1e-12);
+        assert!((eigen.eigenvalues[1] - 3.0).abs() < 1e-12);
+
+        let a = matrix![
+            [4.0, -2.0, 1.0, 0.5],
+            [-2.0, 3.0, 0.0, 1.0],
+            [1.0, 0.0, 5.0, -1.5],
+            [0.5, 1.0, -1.5, 2.0]
+        ];
+        let eigen = a.symmetric_eigen().expect("Jacobi converges");
+        let v = eigen.eigenvectors;
+        let mut lambda = Matrix::<f64, 4, 4>::zeros();
+        for i in 0..4 {
+            lambda.elements[i][i] = eigen.eigenvalues[i];
+        }
+        assert!(eigen.eigenvalues.windows(2).all(|w| w[0] <= w[1]));
+        assert_approx_eq(v.transpose() * v, Matrix::identity(), 1e-9);
+        assert_approx_eq(a * v, v * lambda, 1e-9);
+        let trace: f64 = eigen.eigenvalues.iter().sum();
+        assert!((trace - 14.0).abs() < 1e-9);
+
+        // Covariance ellipse axes in single precision
+        let covariance = matrix![[5.0_f32, 4.0], [4.0, 5.0]];
+        let eigen = covariance.symmetric_eigen().expect("Jacobi converges");
+        assert!((eigen.eigenvalues[0] - 1.0).abs() < 1e-5);
+        assert!((eigen.eigenvalues[1] - 9.0).abs() < 1e-5);
+        let axis = eigen.eigenvectors.elements;
+        assert!((axis[0][1].abs() - 0.5_f32.sqrt()).abs() < 1e-5);
+        assert!((axis[1][1].abs() - 0.5_f32.sqrt()).abs() < 1e-5);
+    }
+
+    fn assert_eigenpairs<const N: usize>(a: Matrix<f64, N, N>) {
+        let eigen = a.eigen().expect("Francis QR converges");
+        for j in 0..N {
+            let lambda = eigen.eigenvalues[j];
+            let mut length = 0.0;
+            for i in 0..N {
+                let mut av = Complex::new(0.0, 0.0);
+                for k in 0..N {
+                    av += eigen.eigenvectors.elements[k][j] * a.elements[i][k];
+                }
+                let residual = av - lambda * eigen.eigenvectors.elements[i][j];
+                assert!(residual.norm() < 1e-9, "{:?} is not an eigenpair of {:?}", lambda, a);
+                length += eigen.eigenvectors.elements[i][j].norm_sqr();
+            }
+            assert!((length - 1.0_f64).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_hessenberg() {
+        let a = matrix![
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0]
+        ];
+        let hessenberg = a.hessenberg();
+        assert_approx_eq(hessenberg.q * hessenberg.h * hessenberg.q.transpose(), a, 1e-9);
+        assert_approx_eq(hessenberg.q.transpose() * hessenberg.q, Matrix::identity(), 1e-9);
+        for i in 2..4 {
+            for j in 0..(i - 1) {
+                assert_eq!(hessenberg.h.elements[i][j], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues() {
+        let rotation = matrix![[0.0, -1.0], [1.0, 0.0]];
+        let eigenvalues = rotation.eigenvalues().expect("Francis QR converges");
+        assert!((eigenvalues[0] - Complex::new(0.0, -1.0)).norm() < 1e-12);
+        assert!((eigenvalues[1] - Complex::new(0.0, 1.0)).norm() < 1e-12);
+
+        // Companion matrix of (s + 1)(s + 2)(s + 3), a stable linear system
+        let a = matrix![[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [-6.0, -11.0, -6.0]];
+        let eigenvalues = a.eigenvalues().expect("Francis QR converges");
+        for (lambda, expected) in eigenvalues.iter().zip([-3.0, -2.0, -1.0]) {
+            assert!((lambda - Complex::new(expected, 0.0)).norm() < 1e-9);
+        }
+        assert!(eigenvalues.iter().all(|lambda| lambda.re < 0.0));
+
+        // Damped oscillator coupled to a decaying mode: -1 ± 2i, -0.5, 3
+        let a = matrix![
+            [-1.0, -2.0, 0.5, 0.0],
+            [2.0, -1.0, 0.0, 1.0],
+            [0.0, 0.0, -0.5, 2.0],
+            [0.0, 0.0, 0.0, 3.0]
+        ];
+        let eigenvalues = a.eigenvalues().expect("Francis QR converges");
+        let expected = [
+            Complex::new(-1.0, -2.0),
+            Complex::new(-1.0, 2.0),
+            Complex::new(-0.5, 0.0),
+            Complex::new(3.0, 0.0),
+        ];
+        for (lambda, expected) in eigenvalues.iter().zip(expected) {
+            assert!((lambda - expected).norm() < 1e-9, "{:?} != {:?}", lambda, expected);
+        }
+
+        assert_eigenpairs(rotation);
+        assert_eigenpairs(a);
+        assert_eigenpairs(matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+        assert_eigenpairs(matrix![
+            [0.5, -1.2, 3.3, 0.1, 2.0],
+            [1.1, 0.0, -0.7, 4.2, -1.0],
+            [-2.5, 0.3, 1.8, 0.6, 0.9],
+            [0.2, 3.1, -1.4, -0.8, 1.7],
+            [1.9, -0.6, 0.4, 2.2, -1.3]
+        ]);
+    }
+
+    #[test]
+    fn test_svd() {
+        let a = matrix![[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]];
+        let svd = a.svd().expect("Jacobi converges");
+        assert!((svd.singular_values[0] - 5.0_f64).abs() < 1e-12);
+        assert!((svd.singular_values[1] - 3.0_f64).abs() < 1e-12);
+        assert_eq!(svd.singular_values[2], 0.0);
+        assert_approx_eq(svd.u * svd.sigma() * svd.v_t, a, 1e-9);
+        assert_approx_eq(svd.u.transpose() * svd.u, Matrix::identity(), 1e-9);
+        assert_approx_eq(svd.v_t * svd.v_t.transpose(), Matrix::identity(), 1e-9);
+
+        let svd = a.transpose().svd().expect("Jacobi converges");
+        assert_eq!(svd.singular_values.len(), 2);
+        assert_approx_eq(svd.u * svd.sigma() * svd.v_t, a.transpose(), 1e-9);
+        assert_approx_eq(svd.u.transpose() * svd.u, Matrix::identity(), 1e-9);
+
+        let a = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0], [5.0, 7.0, 9.0]];
+        let svd = a.svd().expect("Jacobi converges");
+        assert_approx_eq(svd.u * svd.sigma() * svd.v_t, a, 1e-9);
+        assert_approx_eq(svd.u.transpose() * svd.u, Matrix::identity(), 1e-9);
+        assert_eq!(svd.rank(1e-9), 3);
+
+        // Rank two: the third row is the sum of the first two, the fourth their difference
+        let a = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [5.0, 7.0, 9.0], [3.0, 3.0, 3.0]];
+        let svd = a.svd().expect("Jacobi converges");
+        assert_approx_eq(svd.u * svd.sigma() * svd.v_t, a, 1e-9);
+        assert_approx_eq(svd.u.transpose() * svd.u, Matrix::identity(), 1e-9);
+        assert_eq!(svd.rank(1e-9), 2);
+        assert_eq!(a.rank(1e-9), Ok(2));
+        assert!(svd.condition_number() > 1e12);
+    }
+
+    #[test]
+    fn test_svd_large_magnitude() {
+        // α * β overflows here, which must not pass for convergence
+        let a = matrix![[1e80, 2e80], [3e80, 4e80]];
+        let svd = a.svd().expect("Jacobi converges");
+        assert!((svd.singular_values[0] / 1e80 - 5.464985704219043_f64).abs() < 1e-9);
+        assert!((svd.singular_values[1] / 1e80 - 0.365966190626258_f64).abs() < 1e-9);
+        assert_approx_eq((svd.u * svd.sigma() * svd.v_t) * 1e-80, a * 1e-80, 1e-9);
+
+        let a: Matrix<f32, 2, 2> = matrix![[1e10, 2e10], [3e10, 4e10]];
+        let svd = a.svd().expect("Jacobi converges");
+        let usv = svd.u * svd.sigma() * svd.v_t;
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((usv.elements[i][j] - a.elements[i][j]).abs() < 1e-5 * 1e10, "{:?} != {:?}", usv, a);
+            }
+        }
+        assert!((svd.singular_values[0] / 1e10 - 5.464986).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_svd_helpers() {
+        let a = matrix![[2.0, 0.0], [0.0, -0.5]];
+        assert_eq!(a.norm_2(), Ok(2.0));
+        assert_eq!(a.condition_number(), Ok(4.0));
+
+        let a = matrix![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+        let pinv = a.svd().expect("Jacobi converges").pseudo_inverse(1e-12);
+        assert_approx_eq(pinv * a, Matrix::identity(), 1e-9);
+        assert_approx_eq(a * pinv * a, a, 1e-9);
+    }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        // Wide, full row rank: A⁺ = Aᵀ * (A * Aᵀ)⁻¹
+        let a = matrix![[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]];
+        let pinv = a.pseudo_inverse(1e-12).expect("Jacobi converges");
+        let expected = a.transpose() * (a * a.transpose()).inverse().expect("Full row rank");
+        assert_approx_eq(pinv, expected, 1e-9);
+        assert_approx_eq(a * pinv, Matrix::identity(), 1e-9);
+
+        // Rank one, A = u * vᵀ gives A⁺ = v * uᵀ / (|u|² * |v|²)
+        let a = matrix![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+        let svd = a.svd().expect("Jacobi converges");
+        let pinv = a.pseudo_inverse(svd.default_tolerance()).expect("Jacobi converges");
+        assert_approx_eq(pinv, matrix![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]] * (1.0 / 70.0), 1e-9);
+
+        // The four Penrose conditions
+        assert_approx_eq(a * pinv * a, a, 1e-9);
+        assert_approx_eq(pinv * a * pinv, pinv, 1e-9);
+        assert_approx_eq((a * pinv).transpose(), a * pinv, 1e-9);
+        assert_approx_eq((pinv * a).transpose(), pinv * a, 1e-9);
+    }
+}
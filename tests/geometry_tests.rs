@@ -0,0 +1,218 @@
+mod common;
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use crate::common::assert_approx_eq;
+    use numbrs::{
+        compose_rotations_2d, generate_rotation_2d, generate_rotation_3d, generate_rotation_axis_angle,
+        invert_rotation_2d, renormalize_rotation_2d, rotate_vector_2d, rotation_2d, rotation_3d, rotation_angle_2d, vector, Axis, Matrix,
+        Quaternion, UnitQuaternion,
+    };
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+
+    #[test]
+    fn test_rotation_2d() {
+        let R = generate_rotation_2d(FRAC_PI_2);
+        assert_approx_eq(R, Matrix::new([[0.0, -1.0], [1.0, 0.0]]), 1e-12);
+        assert_eq!(rotation_2d!(0.3), generate_rotation_2d(0.3));
+
+        let R: Matrix<f32, 2, 2> = generate_rotation_2d(core::f32::consts::FRAC_PI_6);
+        assert!((R.elements[1][0] - 0.5).abs() < 1e-6);
+
+        let v = vector![[3.0], [4.0]];
+        for angle in [0.0, 0.4, -2.5, PI] {
+            assert_approx_eq(rotate_vector_2d(v, angle).0, (generate_rotation_2d(angle) * v).0, 1e-12);
+        }
+        assert_approx_eq(rotate_vector_2d(v, FRAC_PI_2).0, vector![[-4.0], [3.0]].0, 1e-12);
+    }
+
+    #[test]
+    fn test_rotation_2d_angle_compose_invert() {
+        for angle in [0.0, 1.0, -1.0, 3.0, -3.0, PI] {
+            assert!((rotation_angle_2d(&generate_rotation_2d(angle)) - angle).abs() < 1e-12);
+        }
+        // Angles are wrapped into (-π, π]
+        assert!((rotation_angle_2d(&generate_rotation_2d(3.0 * FRAC_PI_2)) + FRAC_PI_2).abs() < 1e-12);
+
+        let A = generate_rotation_2d(0.5);
+        let B = generate_rotation_2d(1.25);
+        assert_approx_eq(compose_rotations_2d(&A, &B), B * A, 1e-12);
+        assert_approx_eq(compose_rotations_2d(&A, &B), generate_rotation_2d(1.75), 1e-12);
+
+        assert_approx_eq(invert_rotation_2d(&A), generate_rotation_2d(-0.5), 1e-12);
+        assert_approx_eq(invert_rotation_2d(&A) * A, Matrix::identity(), 1e-12);
+        assert_approx_eq(invert_rotation_2d(&A), A.inverse().unwrap(), 1e-12);
+
+        // Many small steps drift only slightly, and renormalizing restores an exact rotation
+        let step = generate_rotation_2d(0.001);
+        let mut R = Matrix::identity();
+        for _ in 0..10_000 {
+            R = compose_rotations_2d(&R, &step);
+        }
+        assert_approx_eq(R * invert_rotation_2d(&R), Matrix::identity(), 1e-9);
+        let R = renormalize_rotation_2d(&R);
+        assert_approx_eq(R * invert_rotation_2d(&R), Matrix::identity(), 1e-12);
+        assert_approx_eq(R, generate_rotation_2d(10.0), 1e-9);
+
+        // Both columns count, not just the first
+        let sheared = Matrix::new([[1.0, -0.2], [0.0, 1.0]]);
+        let R = renormalize_rotation_2d(&sheared);
+        assert!((rotation_angle_2d(&R) - 0.1_f64.atan()).abs() < 1e-12);
+        assert_approx_eq(R * invert_rotation_2d(&R), Matrix::identity(), 1e-12);
+    }
+
+    #[test]
+    fn test_rotation_3d() {
+        let x = vector![[1.0], [0.0], [0.0]];
+        let y = vector![[0.0], [1.0], [0.0]];
+        let z = vector![[0.0], [0.0], [1.0]];
+
+        // Right-handed: X takes y to z, Y takes z to x, Z takes x to y
+        assert_approx_eq((rotation_3d!(FRAC_PI_2, Axis::X) * y).0, z.0, 1e-12);
+        assert_approx_eq((rotation_3d!(FRAC_PI_2, Axis::Y) * z).0, x.0, 1e-12);
+        assert_approx_eq((rotation_3d!(FRAC_PI_2, Axis::Z) * x).0, y.0, 1e-12);
+
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            let R = generate_rotation_3d(0.7, axis);
+            assert_approx_eq(R * R.transpose(), Matrix::identity(), 1e-12);
+            assert!((R.determinant() - 1.0).abs() < 1e-12);
+            assert_approx_eq(R * generate_rotation_3d(-0.7, axis), Matrix::identity(), 1e-12);
+        }
+
+        let R: Matrix<f32, 3, 3> = generate_rotation_3d(core::f32::consts::PI, Axis::Z);
+        assert!((R.elements[0][0] + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rotation_axis_angle() {
+        // Coordinate axes match the elementary rotations
+        let axes = [
+            (vector![[2.0], [0.0], [0.0]], Axis::X),
+            (vector![[0.0], [0.5], [0.0]], Axis::Y),
+            (vector![[0.0], [0.0], [1.0]], Axis::Z),
+        ];
+        for (vector, axis) in axes {
+            assert_approx_eq(generate_rotation_axis_angle(vector, 1.1), generate_rotation_3d(1.1, axis), 1e-12);
+        }
+
+        // A third of a turn about the diagonal cycles the coordinate axes
+        let diagonal = vector![[1.0], [1.0], [1.0]];
+        let R = generate_rotation_axis_angle(diagonal, 2.0 * FRAC_PI_3);
+        assert_approx_eq((R * vector![[1.0], [0.0], [0.0]]).0, vector![[0.0], [1.0], [0.0]].0, 1e-12);
+        assert_approx_eq(R * diagonal.0, diagonal.0, 1e-12);
+
+        let R = generate_rotation_axis_angle(vector![[1.0], [-2.0], [0.5]], PI);
+        assert_approx_eq(R * R, Matrix::identity(), 1e-12);
+        assert!((R.determinant() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_quaternion_algebra() {
+        let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+        let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+        let k = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+        let minus_one = Quaternion::new(-1.0, 0.0, 0.0, 0.0);
+
+        // i² = j² = k² = ijk = -1
+        assert_eq!(i * i, minus_one);
+        assert_eq!(j * j, minus_one);
+        assert_eq!(i * j * k, minus_one);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+
+        let q = Quaternion::new(1.0_f64, -2.0, 0.5, 3.0);
+        let p = Quaternion::new(0.25, 1.0, -1.0, 2.0);
+        assert_eq!(q.conjugate(), Quaternion::new(1.0, 2.0, -0.5, -3.0));
+        assert_eq!((q * p).conjugate(), p.conjugate() * q.conjugate());
+        assert_eq!(q.norm_squared(), 14.25);
+        assert!(((q * p).norm() - q.norm() * p.norm()).abs() < 1e-12);
+        assert!((q.normalize().norm() - 1.0).abs() < 1e-12);
+
+        let product = q * q.inverse();
+        assert!((product - Quaternion::identity()).norm() < 1e-12);
+
+        assert_eq!(Quaternion::from_parts(1.0, vector![[-2.0], [0.5], [3.0]]), q);
+        assert_eq!(q.vector(), vector![[-2.0], [0.5], [3.0]]);
+    }
+
+    #[test]
+    fn test_unit_quaternion_rotation() {
+        let axis = vector![[1.0], [-2.0], [0.5]];
+        let angle = 1.3_f64;
+        let q = UnitQuaternion::from_axis_angle(axis, angle);
+        let R = generate_rotation_axis_angle(axis, angle);
+
+        assert!((q.quaternion().norm() - 1.0).abs() < 1e-12);
+        assert!((q.angle() - angle).abs() < 1e-12);
+        assert_approx_eq(q.to_rotation_matrix(), R, 1e-12);
+
+        let v = vector![[0.3], [2.0], [-1.0]];
+        assert_approx_eq((q * v).0, (R * v).0, 1e-12);
+        assert_approx_eq(q.inverse().rotate(q.rotate(v)).0, v.0, 1e-12);
+
+        // Composition matches matrix multiplication
+        let p = UnitQuaternion::from_axis_angle(vector![[0.0], [1.0], [0.0]], -0.4);
+        assert_approx_eq((p * q).to_rotation_matrix(), p.to_rotation_matrix() * R, 1e-12);
+        assert_approx_eq(((p * q) * v).0, (p * (q * v)).0, 1e-12);
+
+        let q = UnitQuaternion::from_axis_angle(vector![[0.0], [0.0], [1.0]], FRAC_PI_2);
+        assert_approx_eq(q.to_rotation_matrix(), generate_rotation_3d(FRAC_PI_2, Axis::Z), 1e-12);
+        assert_eq!(UnitQuaternion::<f64>::identity().to_rotation_matrix(), Matrix::identity());
+
+        let drifted = UnitQuaternion::new_normalize(Quaternion::new(2.0, 0.0, 0.0, 0.0));
+        assert_eq!(drifted, UnitQuaternion::identity());
+        assert_eq!(drifted.renormalize(), drifted);
+    }
+
+    #[test]
+    fn test_unit_quaternion_from_rotation_matrix() {
+        // Cover every branch of Shepperd's method, including half turns
+        let cases = [
+            (vector![[0.2], [0.3], [1.0]], 0.5),
+            (vector![[1.0], [0.1], [0.1]], PI),
+            (vector![[0.1], [1.0], [0.2]], PI),
+            (vector![[0.1], [0.2], [1.0]], 3.0),
+            (vector![[1.0], [1.0], [1.0]], 0.0),
+        ];
+        for (axis, angle) in cases {
+            let q = UnitQuaternion::from_axis_angle(axis, angle);
+            let back = UnitQuaternion::from_rotation_matrix(&q.to_rotation_matrix());
+            // q and -q are the same rotation
+            let same = (back.quaternion() - q.quaternion()).norm() < 1e-12
+                || (back.quaternion() + q.quaternion()).norm() < 1e-12;
+            assert!(same, "{:?} vs {:?}", back, q);
+            assert_approx_eq(back.to_rotation_matrix(), q.to_rotation_matrix(), 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_unit_quaternion_slerp() {
+        let z = vector![[0.0], [0.0], [1.0]];
+        let a = UnitQuaternion::from_axis_angle(z, 0.2_f64);
+        let b = UnitQuaternion::from_axis_angle(z, 1.4);
+
+        assert!((a.slerp(&b, 0.0).angle() - 0.2).abs() < 1e-12);
+        assert!((a.slerp(&b, 1.0).angle() - 1.4).abs() < 1e-12);
+        assert!((a.slerp(&b, 0.25).angle() - 0.5).abs() < 1e-12);
+        assert_approx_eq(
+            a.slerp(&b, 0.5).to_rotation_matrix(),
+            generate_rotation_3d(0.8, Axis::Z),
+            1e-12,
+        );
+
+        // Takes the short way round when the quaternions are in opposite hemispheres
+        let c = UnitQuaternion::from_axis_angle(z, -0.3 + 2.0 * PI);
+        let halfway = UnitQuaternion::<f64>::identity().slerp(&c, 0.5);
+        assert_approx_eq(halfway.to_rotation_matrix(), generate_rotation_3d(-0.15, Axis::Z), 1e-12);
+
+        // Nearly identical rotations use the linear fallback and stay unit
+        let d = UnitQuaternion::from_axis_angle(z, 0.2 + 1e-9);
+        let mid = a.slerp(&d, 0.5);
+        assert!((mid.quaternion().norm() - 1.0).abs() < 1e-12);
+        assert!((mid.angle() - 0.2).abs() < 1e-8);
+
+        let f = UnitQuaternion::from_axis_angle(vector![[1.0_f32], [0.0], [0.0]], 1.0);
+        assert!((UnitQuaternion::identity().slerp(&f, 0.5).angle() - 0.5).abs() < 1e-6);
+    }
+}
@@ -1,6 +1,10 @@
+mod common;
+
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
-    use numbrs::{identity_matrix, matrix, vector, Matrix, complex};
+    use crate::common::assert_approx_eq;
+    use numbrs::{block_matrix, complex, identity_matrix, matrix, vector, Complex, LinalgError, Matrix};
 
     #[test]
     fn test_matrix_macro() {
@@ -53,7 +57,7 @@ mod tests {
     fn test_matrix_add() {
         let A = matrix![[1, 3], [2, -1],];
         let B = matrix![[2, 1], [0, 1],];
-        let C = matrix![[1, 0, 1], [0, -1, 1],];
+        let _C = matrix![[1, 0, 1], [0, -1, 1],];
 
         let res = A + B;
         assert_eq!(res, matrix![[3, 4], [2, 0],]);
@@ -95,6 +99,245 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_matrix_indexing() {
+        let mut A = matrix![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(A[(0, 2)], 3);
+        assert_eq!(A[(1, 0)], 4);
+
+        A[(1, 1)] = -5;
+        assert_eq!(A, matrix![[1, 2, 3], [4, -5, 6]]);
+
+        assert_eq!(A.get(1, 2), Some(&6));
+        assert_eq!(A.get(2, 0), None);
+        assert_eq!(A.get(0, 3), None);
+        if let Some(x) = A.get_mut(0, 0) {
+            *x = 10;
+        }
+        assert_eq!(A.get_mut(5, 5), None);
+        assert_eq!(A[(0, 0)], 10);
+
+        assert_eq!(A.row(1), vector![[4, -5, 6]]);
+        assert_eq!(A.column(2), vector![[3], [6]]);
+        assert_eq!(A.row(0)[1], 2);
+        assert_eq!(A.column(1)[1], -5);
+    }
+
+    #[test]
+    fn test_matrix_fixed_view() {
+        let mut A = matrix![
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12],
+        ];
+
+        let view = A.fixed_view::<2, 2>(1, 2);
+        assert_eq!(view, matrix![[7, 8], [11, 12]]);
+        assert_eq!(view[(1, 0)], 11);
+        assert_eq!(view + matrix![[1, 1], [1, 1]], matrix![[8, 9], [12, 13]]);
+        assert_eq!(view - A.fixed_view::<2, 2>(0, 0), matrix![[6, 6], [6, 6]]);
+        assert_eq!(view * matrix![[1], [-1]], matrix![[-1], [-1]]);
+        assert_eq!(matrix![[1, 0]] * view, matrix![[7, 8]]);
+        assert_eq!(A.fixed_block::<1, 4>(2, 0), matrix![[9, 10, 11, 12]]);
+
+        assert_eq!(A.try_fixed_view::<2, 2>(2, 0).unwrap_err(), LinalgError::DimensionMismatch);
+        assert_eq!(A.try_fixed_view::<3, 4>(0, 0).unwrap(), A);
+        assert_eq!(A.try_fixed_block::<2, 1>(1, 3), Ok(matrix![[8], [12]]));
+        assert_eq!(A.try_fixed_block::<2, 1>(2, 3), Err(LinalgError::DimensionMismatch));
+
+        let mut block = A.fixed_view_mut::<2, 2>(0, 0);
+        block[(0, 0)] = 0;
+        block += matrix![[10, 10], [10, 10]];
+        assert_eq!(block.to_matrix(), matrix![[10, 12], [15, 16]]);
+
+        A.set_block(1, 1, matrix![[0, 0, 0], [0, 0, 0]]);
+        assert_eq!(A, matrix![[10, 12, 3, 4], [15, 0, 0, 0], [9, 0, 0, 0],]);
+
+        A.fixed_view_mut::<3, 1>(0, 3).fill(-1);
+        assert_eq!(A.column(3), vector![[-1], [-1], [-1]]);
+
+        assert_eq!(A.try_set_block(0, 3, matrix![[1, 1]]), Err(LinalgError::DimensionMismatch));
+    }
+
+    #[test]
+    fn test_matrix_stacking() {
+        let A = matrix![[1, 2], [3, 4]];
+        let b = matrix![[5], [6]];
+
+        let Ab: Matrix<i32, 2, 3> = A.hstack(b);
+        assert_eq!(Ab, matrix![[1, 2, 5], [3, 4, 6]]);
+
+        let Ac: Matrix<i32, 3, 2> = A.vstack(matrix![[7, 8]]);
+        assert_eq!(Ac, matrix![[1, 2], [3, 4], [7, 8]]);
+
+        // Augmented state transition for a constant-velocity model with a bias term
+        let dt = 0.5;
+        let F = matrix![[1.0, dt], [0.0, 1.0]];
+        let B = matrix![[0.5 * dt * dt], [dt]];
+        let F_aug: Matrix<f64, 3, 3> = block_matrix![
+            [F, B],
+            [Matrix::<f64, 1, 2>::zeros(), Matrix::<f64, 1, 1>::identity()],
+        ];
+        assert_eq!(
+            F_aug,
+            matrix![[1.0, 0.5, 0.125], [0.0, 1.0, 0.5], [0.0, 0.0, 1.0]]
+        );
+
+        let K: Matrix<i32, 4, 4> = block_matrix![
+            [Matrix::<i32, 2, 2>::identity(), A],
+            [A, Matrix::<i32, 2, 2>::zeros()],
+        ];
+        assert_eq!(K.fixed_block::<2, 2>(0, 2), A);
+        assert_eq!(K.fixed_block::<2, 2>(2, 0), A);
+        assert_eq!(K.fixed_block::<2, 2>(2, 2), Matrix::zeros());
+    }
+
+    #[test]
+    fn test_matrix_iterators() {
+        let mut A = matrix![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(A.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(A.iter().max(), Some(&6));
+
+        for x in A.iter_mut() {
+            *x *= 10;
+        }
+        assert_eq!(A, matrix![[10, 20, 30], [40, 50, 60]]);
+
+        let rows: Vec<_> = A.rows().collect();
+        assert_eq!(rows, vec![vector![[10, 20, 30]], vector![[40, 50, 60]]]);
+        assert_eq!(A.rows().next_back(), Some(vector![[40, 50, 60]]));
+
+        let columns: Vec<_> = A.columns().collect();
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[1], vector![[20], [50]]);
+        assert_eq!(A.columns().len(), 3);
+    }
+
+    #[test]
+    fn test_matrix_map_zip_fold() {
+        let A = matrix![[1, -2], [3, -4]];
+        let B = matrix![[2, 2], [0, 1]];
+
+        assert_eq!(A.map(|x| x * x), matrix![[1, 4], [9, 16]]);
+        assert_eq!(A.map(|x| x as f64 / 2.0), matrix![[0.5, -1.0], [1.5, -2.0]]);
+        assert_eq!(A.zip_map(&B, |a, b| a.max(b)), matrix![[2, 2], [3, 1]]);
+        assert_eq!(A.zip_map(&B, |a, b| a < b).elements, [[true, true], [false, true]]);
+
+        assert_eq!(A.fold(0, |acc, x| acc + x), -2);
+        assert_eq!(A.fold(0, |acc, x: i32| acc.max(x.abs())), 4);
+
+        // The elementwise operators are built on these
+        assert_eq!(A + B, A.zip_map(&B, |a, b| a + b));
+        assert_eq!(A * 3, A.map(|x| 3 * x));
+    }
+
+    #[test]
+    fn test_matrix_component_ops() {
+        let A = matrix![[1, 2], [3, 4]];
+        let B = matrix![[5, 6], [7, 8]];
+        assert_eq!(A.component_mul(&B), matrix![[5, 12], [21, 32]]);
+        assert_eq!(B.component_div(&A), matrix![[5, 3], [2, 2]]);
+
+        let C = matrix![[1.0, 2.0], [4.0, 8.0]];
+        assert_eq!(C.component_div(&C), Matrix::ones());
+    }
+
+    #[test]
+    fn test_matrix_kron() {
+        let A = matrix![[1, 2], [3, 4]];
+        let B = matrix![[0, 5], [6, 7]];
+        let AB: Matrix<i32, 4, 4> = A.kron(&B);
+        assert_eq!(
+            AB,
+            matrix![
+                [0, 5, 0, 10],
+                [6, 7, 12, 14],
+                [0, 15, 0, 20],
+                [18, 21, 24, 28],
+            ]
+        );
+
+        // Two agents sharing the same dynamics: I ⊗ F is block diagonal
+        let F = matrix![[1.0, 0.1], [0.0, 1.0]];
+        let F2: Matrix<f64, 4, 4> = Matrix::<f64, 2, 2>::identity().kron(&F);
+        assert_eq!(F2.fixed_block::<2, 2>(0, 0), F);
+        assert_eq!(F2.fixed_block::<2, 2>(2, 2), F);
+        assert_eq!(F2.fixed_block::<2, 2>(0, 2), Matrix::zeros());
+
+        // Non-square factors
+        let row = matrix![[1, -1]];
+        let col = matrix![[2], [3]];
+        let rc: Matrix<i32, 2, 2> = row.kron(&col);
+        assert_eq!(rc, matrix![[2, -2], [3, -3]]);
+
+        // (A ⊗ B)(C ⊗ D) = AC ⊗ BD
+        let C = matrix![[1, 0], [2, 1]];
+        let D = matrix![[1, 1], [0, 1]];
+        let CD: Matrix<i32, 4, 4> = C.kron(&D);
+        let ACBD: Matrix<i32, 4, 4> = (A * C).kron(&(B * D));
+        assert_eq!(AB * CD, ACBD);
+    }
+
+    #[test]
+    fn test_matrix_reductions() {
+        let A = matrix![[2, -7, 1], [4, 0, -3], [5, 6, 9]];
+        assert_eq!(A.trace(), 11);
+        assert_eq!(A.sum(), 17);
+        assert_eq!(matrix![[1, 2], [3, 4]].product(), 24);
+
+        assert_eq!(A.min(), Some(-7));
+        assert_eq!(A.argmin(), Some((0, 1)));
+        assert_eq!(A.max(), Some(9));
+        assert_eq!(A.argmax(), Some((2, 2)));
+
+        // Ties resolve to the first element in row-major order
+        assert_eq!(matrix![[1, 3], [3, 1]].argmax(), Some((0, 1)));
+
+        let empty = Matrix::<f64, 0, 0>::zeros();
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.argmax(), None);
+        assert_eq!(empty.trace(), 0.0);
+
+        // NaN never wins a comparison
+        let B = matrix![[f64::NAN, 1.0], [-2.0, f64::NAN]];
+        assert_eq!(B.min(), Some(-2.0));
+        assert_eq!(B.argmax(), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_matrix_norms() {
+        let A = matrix![[1, -2, 3], [-4, 5, -6]];
+        assert_eq!(A.norm_1(), 9);
+        assert_eq!(A.norm_inf(), 15);
+        assert_eq!(A.max_abs(), 6);
+
+        let B = matrix![[3.0_f64, 0.0], [-4.0, 0.0]];
+        assert_eq!(B.frobenius_norm(), 5.0);
+        assert_eq!(matrix![[1.0, 2.0], [2.0, 4.0]].frobenius_norm(), 5.0);
+
+        // The 2-norm of a rank-one matrix u vᵀ is |u| |v|
+        assert!((B.norm_2_estimate(1e-12) - 5.0).abs() < 1e-12);
+
+        let C = matrix![[4.0_f64, 1.0, -2.0], [1.0, 2.0, 0.0], [-2.0, 0.0, 3.0], [0.5, -1.0, 1.0]];
+        let exact = C.norm_2().unwrap();
+        let estimate = C.norm_2_estimate(1e-12);
+        assert!(estimate <= exact * (1.0 + 1e-12));
+        assert!((estimate - exact).abs() < 1e-9 * exact, "{} vs {}", estimate, exact);
+
+        // Norm inequalities: |A|₂ ≤ |A|_F and |A|₂² ≤ |A|₁ |A|∞
+        assert!(exact <= C.frobenius_norm());
+        assert!(exact * exact <= C.norm_1() * C.norm_inf());
+
+        assert_eq!(Matrix::<f64, 3, 2>::zeros().norm_2_estimate(1e-12), 0.0);
+
+        // Squaring entries of this size overflows f32 unless the iteration is rescaled
+        let D = matrix![[1e10_f32, 2e10], [3e10, 4e10]];
+        let estimate = D.norm_2_estimate(1e-6);
+        assert!((estimate / 5.464986e10 - 1.0).abs() < 1e-5, "{}", estimate);
+        assert!(estimate <= D.frobenius_norm());
+    }
+
     #[test]
     fn test_matrix_power() {
         let A = matrix![[2, 1], [-1, 3],];
@@ -128,25 +371,73 @@ mod tests {
 
     #[test]
     fn test_matrix_inverse() {
-        let c = matrix![[4,7],[2,6]];
+        let c = matrix![[4.0, 7.0], [2.0, 6.0]];
         let res = c.inverse().expect("Matrix is not invertible");
-        println!("{:?}", res);
-        assert_eq!(res, matrix![[0.6, -0.7], [-0.2, 0.4],]);
+        assert_approx_eq(res, matrix![[0.6, -0.7], [-0.2, 0.4],], 1e-9);
+
+        // Small entries must survive without being rounded away
+        let c = matrix![[1e-8_f32, 0.0], [0.0, 4e-9]];
+        let res = c.inverse().expect("Matrix is not invertible");
+        assert_eq!(res, matrix![[1e8_f32, 0.0], [0.0, 2.5e8]]);
+
+        let singular = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        assert_eq!(singular.inverse(), Err(LinalgError::Singular));
     }
 
     #[test]
-    fn test_matrix_decomposition() {
-        // let c = matrix![[3,1,6], [-6,0,-16], [0,8,-17]];
-        // let res = c.lu_decomposition();
-        // let L = res.0;
-        // let U = res.1;
-
-        // // assert_eq!(L, matrix![[1,0,0], [-2,1,0], [0,4,1],]);
-        // assert_eq!(U, matrix![[3,1,6], [0,2,-4], [0,0,-1]]);
+    fn test_matrix_inverse_mixed_scale() {
+        // Badly scaled but perfectly conditioned row by row, as in filter covariances
+        let a = matrix![[100.0_f32, 0.0, 0.0], [0.0, 100.0, 0.0], [0.0, 0.0, 1e-6]];
+        let inv = a.inverse().expect("Matrix is not singular");
+        assert_eq!(inv.elements[2][2], 1e6);
+        let chol_inv = a.cholesky().expect("Matrix is positive definite").inverse();
+        assert!((inv.elements[2][2] - chol_inv.elements[2][2]).abs() < 1.0);
+        let x = a.solve(vector![[100.0], [200.0], [3e-6]]).expect("Matrix is not singular");
+        assert!((x.0.elements[2][0] - 3.0).abs() < 1e-5);
+
+        let a = matrix![[1.0, 0.0], [0.0, 1e-17]];
+        let inv = a.inverse().expect("Matrix is not singular");
+        assert_eq!(inv, matrix![[1.0, 0.0], [0.0, 1e17]]);
+        let x = a.solve(vector![[2.0], [3e-17]]).expect("Matrix is not singular");
+        assert_approx_eq(x.0, matrix![[2.0], [3.0]], 1e-9);
+    }
 
-        let c = matrix![[-2,1,3], [-4,4,1]];
-        // let res = c.lu_decomposition();
+    #[test]
+    fn test_matrix_decomposition() {
+        let a = matrix![[2.0, 4.0, 1.0], [4.0, 2.0, 2.0], [1.0, 2.0, 4.0]];
+        let lu = a.partial_piv_lu();
+
+        assert_eq!(lu.perm, [1, 0, 2]);
+        assert_eq!(lu.sign, -1.0);
+        assert_eq!(lu.p, matrix![[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert_eq!(lu.l, matrix![[1.0, 0.0, 0.0], [0.5, 1.0, 0.0], [0.25, 0.5, 1.0]]);
+        assert_eq!(lu.u, matrix![[4.0, 2.0, 2.0], [0.0, 3.0, 0.0], [0.0, 0.0, 3.5]]);
+        assert_eq!(lu.p * a, lu.l * lu.u);
+
+        // Row swaps in later columns must carry the already computed multipliers along
+        let a = matrix![[1.0, 2.0, 1.0], [2.0, 3.0, 5.0], [4.0, 6.0, 8.0]];
+        let lu = a.lu_decomposition();
+        assert_eq!(lu.perm, [2, 0, 1]);
+        assert_eq!(lu.sign, 1.0);
+        assert_eq!(lu.l, matrix![[1.0, 0.0, 0.0], [0.25, 1.0, 0.0], [0.5, 0.0, 1.0]]);
+        assert_eq!(lu.p * a, lu.l * lu.u);
+    }
 
+    #[test]
+    fn test_matrix_solve() {
+        let a = matrix![[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]];
+        let b = vector![[5.0], [-2.0], [9.0]];
+        let x = a.solve(b).expect("Matrix is not invertible");
+        assert_approx_eq(x.0, matrix![[1.0], [1.0], [2.0]], 1e-9);
+
+        let lu = a.partial_piv_lu();
+        let b = matrix![[5.0, 4.0], [-2.0, -2.0], [9.0, 7.0]];
+        let x = lu.solve_matrix(b).expect("Matrix is not invertible");
+        assert_approx_eq(x, matrix![[1.0, 1.0], [1.0, 1.0], [2.0, 1.0]], 1e-9);
+        assert_approx_eq(a * x, b, 1e-9);
+
+        let singular = matrix![[1.0, 2.0], [2.0, 4.0]];
+        assert_eq!(singular.solve(vector![[1.0], [2.0]]), Err(LinalgError::Singular));
     }
 
     #[test]
@@ -169,6 +460,89 @@ mod tests {
         ];
         let res = a.determinant();
         assert_eq!(res, -4008);
+
+        let a = matrix![[0, 2, 1], [3, 0, 0], [1, 1, 1]];
+        assert_eq!(a.determinant(), -3);
+
+        let a = matrix![[2.0_f64, 4.0, 1.0], [4.0, 2.0, 2.0], [1.0, 2.0, 4.0]];
+        assert!((a.determinant() + 42.0).abs() < 1e-12);
+        assert!((a.partial_piv_lu().determinant() + 42.0).abs() < 1e-12);
+
+        // The second-difference matrix of size n has determinant n + 1
+        let mut a = Matrix::<i64, 10, 10>::zeros();
+        let mut b = Matrix::<f64, 10, 10>::zeros();
+        for i in 0..10 {
+            a.elements[i][i] = 2;
+            b.elements[i][i] = 2.0;
+            if i > 0 {
+                a.elements[i][i - 1] = -1;
+                b.elements[i][i - 1] = -1.0;
+            }
+            if i < 9 {
+                a.elements[i][i + 1] = -1;
+                b.elements[i][i + 1] = -1.0;
+            }
+        }
+        assert_eq!(a.determinant(), 11);
+        assert!((b.determinant() - 11.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_dyn_matrix_determinant() {
+        // Dynamic matrices use fraction-free elimination rather than cofactor expansion
+        let mut a = Matrix::<i64, 10, 10>::zeros();
+        for i in 0..10 {
+            a.elements[i][i] = 2;
+            if i > 0 {
+                a.elements[i][i - 1] = -1;
+            }
+            if i < 9 {
+                a.elements[i][i + 1] = -1;
+            }
+        }
+        assert_eq!(a.to_dyn().determinant(), 11);
+        assert_eq!(matrix![[0, 2, 1], [3, 0, 0], [1, 1, 1]].to_dyn().determinant(), -3);
+        assert!((a.map(|x| x as f64).to_dyn().determinant() - 11.0).abs() < 1e-9);
+
+        let a = matrix![
+            [complex!(0), complex!(1 + 1 i), complex!(2)],
+            [complex!(1), complex!(0), complex!(0 + 1 i)],
+            [complex!(2 - 1 i), complex!(1), complex!(0)],
+        ];
+        assert!((a.to_dyn().determinant() - complex!(1 + 3 i)).norm() < 1e-12);
+
+        // Pivoting on the first non-zero entry would cancel everything here and give 0
+        let a = matrix![[1e-20_f64, 1.0, 1.0], [1.0, 1.0, 2.0], [1.0, 2.0, 1.0]];
+        assert!((a.to_dyn().determinant() - 2.0).abs() < 1e-12);
+        assert!((a.to_dyn().determinant() - a.determinant()).abs() < 1e-12);
+
+        let a = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 10]].to_dyn();
+        assert_eq!(a.minor(1, 1).data, vec![1, 3, 7, 10]);
+        assert_eq!(a.try_minor(3, 0).err(), Some(LinalgError::DimensionMismatch));
+    }
+
+    #[test]
+    fn test_matrix_complex_determinant() {
+        let a = matrix![[complex!(1 + 2 i), complex!(3)], [complex!(1), complex!(2 - 1 i)]];
+        assert!((a.determinant() - complex!(1 + 3 i)).norm() < 1e-12);
+
+        // Needs a row swap: the leading entry is zero
+        let a = matrix![
+            [complex!(0), complex!(1 + 1 i), complex!(2)],
+            [complex!(1), complex!(0), complex!(0 + 1 i)],
+            [complex!(2 - 1 i), complex!(1), complex!(0)],
+        ];
+        assert!((a.determinant() - complex!(1 + 3 i)).norm() < 1e-12);
+
+        let singular = matrix![[complex!(1 + 1 i), complex!(2)], [complex!(1 + 1 i), complex!(2)]];
+        assert_eq!(singular.determinant(), complex!(0));
+
+        let a = Matrix::new([
+            [Complex::new(1.0_f32, 1.0), Complex::new(0.0, 0.0)],
+            [Complex::new(0.0, 0.0), Complex::new(1.0, -1.0)],
+        ]);
+        assert_eq!(a.determinant(), Complex::new(2.0, 0.0));
     }
 
 
@@ -1,6 +1,7 @@
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
-    use numbrs::{matrix, vector, Matrix};
+    use numbrs::{complex, matrix, vector, LinalgError, Matrix, Vector};
 
     #[test]
     fn test_vector_macro() {
@@ -35,6 +36,19 @@ mod tests {
         assert_eq!(filled_int, matrix![[5, 5, 5, 5]]);
     }
 
+    #[test]
+    fn test_vector_indexing() {
+        let mut v = vector![[1.0, 2.0, 3.0]];
+        assert_eq!(v[2], 3.0);
+        v[0] = -1.0;
+        assert_eq!(v, vector![[-1.0, 2.0, 3.0]]);
+
+        let mut w = vector![[4], [5], [6]];
+        assert_eq!(w[1], 5);
+        w[2] += 1;
+        assert_eq!(w, vector![[4], [5], [7]]);
+    }
+
     #[test]
     fn test_vector_add() {
         let v1 = vector![[1.0, 2.0, 3.0]];
@@ -66,10 +80,10 @@ mod tests {
     #[test]
     fn test_vector_mul() {
         let v1 = vector![[1.0, 2.0, 3.0]];
-        let v2 = v1.clone() * 2.0;
+        let v2 = v1 * 2.0;
         assert_eq!(v2, vector![[2.0, 4.0, 6.0]]);
 
-        let v3 = v1.clone() * 5;
+        let v3 = v1 * 5;
         assert_eq!(v3, vector![[5.0, 10.0, 15.0]]);
 
         // TODO: Implement Mul for Vector<T> where T: Num + Copy
@@ -77,6 +91,21 @@ mod tests {
         // assert_eq!(v4, vector![5.0, 10.0, 15.0]);
     }
 
+    #[test]
+    fn test_vector_outer() {
+        let u = vector![[1], [2], [3]];
+        let v = vector![[4], [5]];
+        let uv = u.outer(&v);
+        assert_eq!(uv, matrix![[4, 5], [8, 10], [12, 15]]);
+
+        let r = vector![[1, 2, 3]];
+        let s = vector![[4, 5]];
+        assert_eq!(r.outer(&s), uv);
+
+        // Same as the column-times-row product
+        assert_eq!(u * s, uv);
+    }
+
     #[test]
     fn test_vector_dot() {
         let v1 = vector![[1.0, 2.0, 3.0]];
@@ -96,12 +125,23 @@ mod tests {
         let v = vector![[1,2,3]];
         let w = vector![[-3,2,-1]];
         let dot = v * w;
-        assert_eq!(dot, -2.0);
+        assert_eq!(dot, -2);
 
         let v = vector![[3,-1,0,1]];
         let w = vector![[0,2,1,3]];
         let dot = v * w;
-        assert_eq!(dot, 1.0);
+        assert_eq!(dot, 1);
+        assert_eq!(v.dot_f64(&w), 1.0);
+
+        // Integer products stay exact, even past f64's 53-bit mantissa
+        let v = vector![[i64::MAX / 2, 1]];
+        let w = vector![[2, 1]];
+        assert_eq!(v.dot(&w), i64::MAX);
+
+        let v = vector![[1.5_f32], [-2.0], [0.5]];
+        let w = vector![[2.0_f32], [1.0], [4.0]];
+        assert_eq!(v * w, 3.0_f32);
+        assert_eq!(v.dot(&w), 3.0_f32);
 
         // let v = sqrt_vector![2,3,5];
         // let sqrt2 = 2.0_f64.sqrt();
@@ -126,24 +166,34 @@ mod tests {
     fn test_vector_magnitude() {
         let v = vector![[1.0, 2.0, 3.0]];
         let mag = v.magnitude();
-        assert_eq!(mag, (14.0 as f64).sqrt());
+        assert_eq!(mag, 14.0_f64.sqrt());
 
         let v = vector![[1, 2, 3]];
-        let mag = v.magnitude();
-        assert_eq!(mag, (14.0 as f64).sqrt());
+        let mag = v.magnitude_f64();
+        assert_eq!(mag, 14.0_f64.sqrt());
 
         let v = vector![[2,-5,4,6]];
-        let mag = v.magnitude();
+        let mag = v.magnitude_f64();
         assert_eq!(mag, 9.0);
 
         let v = vector![[3,4]];
-        let mag = v.magnitude();
+        let mag = v.magnitude_f64();
         assert_eq!(mag, 5.0);
 
         let v = vector![[2,1,-2]];
-        let mag = v.magnitude();
+        let mag = v.magnitude_f64();
         assert_eq!(mag, 3.0);
 
+        // Float vectors stay in their own precision
+        let v = vector![[3.0_f32, 4.0]];
+        let mag: f32 = v.magnitude();
+        assert_eq!(mag, 5.0);
+        assert_eq!(v.normalize(), vector![[0.6_f32, 0.8]]);
+
+        let v = vector![[0.0_f32], [-2.0], [0.0]];
+        assert_eq!(v.magnitude(), 2.0);
+        assert_eq!(v.normalize(), vector![[0.0_f32], [-1.0], [0.0]]);
+
         // TODO: how to support this?
         // let elements = [
         //     -2.0 * (2.0_f64).sqrt(), // -2√2
@@ -156,6 +206,133 @@ mod tests {
         // assert_eq!(mag, 6.0);
     }
 
+    #[test]
+    fn test_vector_angle_between() {
+        let x = vector![[1.0_f32, 0.0]];
+        let y = vector![[0.0_f32, 2.0]];
+        assert!((x.angle_between(&y) - core::f32::consts::FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(x.angle_between(&(x * 3.0)), 0.0);
+
+        // Rounding must not turn the angle between parallel vectors into NaN
+        for v in [vector![[0.1_f64], [0.2], [0.3]], vector![[1.0 / 3.0], [0.7], [-5.1]]] {
+            let angle = v.angle_between(&(v * 7.0));
+            assert!(angle < 1e-7, "{}", angle);
+        }
+
+        // A zero-length vector has no direction, so there is no angle
+        let zero = vector![[0.0_f32, 0.0]];
+        assert!(zero.angle_between(&x).is_nan());
+        assert!(x.angle_between(&zero).is_nan());
+        assert!(vector![[0, 0]].angle_between_f64(&vector![[1, 0]]).is_nan());
+    }
+
+    #[test]
+    fn test_vector_trait() {
+        // State vectors are columns, so everything must work on them too
+        let p = vector![[1.0], [2.0], [2.0]];
+        let q = vector![[4.0], [6.0], [2.0]];
+
+        assert_eq!(p.norm_squared(), 9.0);
+        assert_eq!(p.norm(), 3.0);
+        assert_eq!(p.magnitude(), p.norm());
+        assert_eq!(p.distance(&q), 5.0);
+        assert_eq!(q.distance(&p), 5.0);
+        assert_eq!(p.lerp(&q, 0.0), p);
+        assert_eq!(p.lerp(&q, 1.0), q);
+        assert_eq!(p.lerp(&q, 0.5), vector![[2.5], [4.0], [2.0]]);
+
+        let n = q.normalize();
+        assert!((n.norm() - 1.0_f64).abs() < 1e-12);
+        assert!(n.angle_between(&q) < 1e-7);
+
+        let x = vector![[2.0], [0.0], [0.0]];
+        assert_eq!(q.project_onto(&x), vector![[4.0], [0.0], [0.0]]);
+        let residual = q - q.project_onto(&p);
+        assert!(residual.dot(&p).abs() < 1e-12);
+
+        // Row vectors share the same implementation
+        let r = vector![[1.0_f32, 2.0, 2.0]];
+        assert_eq!(r.norm(), 3.0);
+        assert_eq!(r.project_onto(&vector![[0.0, 1.0, 0.0]]), vector![[0.0, 2.0, 0.0]]);
+
+        // Integer vectors get the operations that need no division
+        let i = vector![[1], [2], [3]];
+        assert_eq!(i.norm_squared(), 14);
+        assert_eq!(i.lerp(&vector![[3], [2], [1]], 2), vector![[5], [2], [-1]]);
+    }
+
+    #[test]
+    fn test_vector_cross() {
+        let x = vector![[1], [0], [0]];
+        let y = vector![[0], [1], [0]];
+        let z = vector![[0], [0], [1]];
+        assert_eq!(x.cross(&y), z);
+        assert_eq!(y.cross(&z), x);
+        assert_eq!(y.cross(&x), z * -1);
+
+        let a = vector![[2, 3, 4]];
+        let b = vector![[5, 6, 7]];
+        let c = a.cross(&b);
+        assert_eq!(c, vector![[-3, 6, -3]]);
+        assert_eq!(c.dot(&a), 0);
+        assert_eq!(c.dot(&b), 0);
+        assert_eq!(a.cross(&a), vector![[0, 0, 0]]);
+
+        // |a × b|² = |a|²|b|² - (a · b)²
+        assert_eq!(c.norm_squared(), a.norm_squared() * b.norm_squared() - a.dot(&b) * a.dot(&b));
+    }
+
+    #[test]
+    fn test_vector_skew_and_triple_product() {
+        let v = vector![[1.0], [-2.0], [0.5]];
+        let w = vector![[3.0], [1.0], [4.0]];
+
+        let V = v.skew();
+        assert_eq!(V, Matrix::from_skew(v));
+        assert_eq!(V.transpose(), V * -1.0);
+        assert_eq!(V * w, v.cross(&w));
+        assert_eq!(V.vee(), v);
+
+        let r = vector![[1.0, -2.0, 0.5]];
+        assert_eq!(r.skew(), V);
+
+        let x = vector![[1], [0], [0]];
+        let y = vector![[0], [1], [0]];
+        let z = vector![[0], [0], [1]];
+        assert_eq!(x.triple_product(&y, &z), 1);
+        assert_eq!(y.triple_product(&x, &z), -1);
+
+        // Equals the determinant of the matrix with the vectors as rows
+        let a = vector![[2, 0, 1]];
+        let b = vector![[1, 3, -1]];
+        let c = vector![[0, 1, 4]];
+        let rows = matrix![[2, 0, 1], [1, 3, -1], [0, 1, 4]];
+        assert_eq!(a.triple_product(&b, &c), rows.determinant());
+        assert_eq!(a.triple_product(&b, &c), 27);
+    }
+
+    #[test]
+    fn test_vector_try_conversions() {
+        let v = vector![[3, 4]];
+        assert_eq!(v.try_magnitude_f64(), Ok(5.0));
+        assert_eq!(v.try_dot_f64(&vector![[1, 1]]), Ok(7.0));
+        assert_eq!(v.angle_between_f64(&v), 0.0);
+
+        // Rounding can push cos θ just past 1; it must be clamped rather than give NaN
+        let v = vector![[1, 1, 1]];
+        assert_eq!(v.angle_between_f64(&v), 0.0);
+
+        let v = vector![[3], [4]];
+        assert_eq!(v.magnitude_f64(), 5.0);
+
+        let v = vector![[complex!(1 + 2 i), complex!(3)]];
+        assert_eq!(v.try_magnitude_f64(), Err(LinalgError::ConversionFailed));
+        assert_eq!(v.try_angle_between_f64(&v), Err(LinalgError::ConversionFailed));
+
+        // The generic dot product works where the f64 one cannot
+        assert_eq!(v.dot(&v), complex!(6 + 4 i));
+    }
+
     // #[test]
     // fn test_vector_angles() {
     //     let v = vector![[1,2]];